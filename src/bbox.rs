@@ -1,23 +1,51 @@
+#[cfg(any(test, feature = "proptest"))]
+pub(crate) mod arbitrary;
+mod axis_range;
 mod bound_tuple;
+mod builder;
+mod corner_iter;
+mod dyn_bbox;
+mod parse;
+mod periodic;
 mod range;
 mod range_from;
 mod range_full;
 mod range_inclusive;
 mod range_to;
 mod range_to_inclusive;
+#[cfg(feature = "rand")]
+mod sample;
+mod snap;
 mod utils;
-
-use std::cmp::{max, min};
-use std::ops::{Bound, Index, IndexMut, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
-use std::ops::Bound::{Excluded, Included, Unbounded};
-use std::slice::{Iter, IterMut};
-use na::{ClosedAdd, ClosedSub, Point, Scalar, SVector};
-use num_traits::{One, Zero};
-use crate::{Holds, Intersection, IsRangeEmpty, PointBounds, Walkable};
-use crate::bbox::utils::{max_bound, min_bound};
+mod wrap;
+
+use core::cmp::Ordering;
+use core::ops::{Bound, Index, IndexMut, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+use core::ops::Bound::{Excluded, Included, Unbounded};
+use core::slice::{Iter, IterMut};
+use alloc::{vec, vec::Vec};
+use na::{ClosedAdd, ClosedDiv, ClosedMul, ClosedSub, Matrix3, Matrix4, Point, Scalar, SVector};
+use num_traits::{CheckedAdd, CheckedSub, One, PrimInt, Zero};
+use num_traits::float::FloatCore;
+use crate::{BBoxWalker, Holds, Intersection, IntoBBox, IntoPoint, IsRangeEmpty, PointBounds, Segment, Walkable};
+use crate::bbox::utils::{cmp_distances, lattice_end, lattice_start, max_bound, max_end_bound, min_bound, min_start_bound, partial_max, partial_min};
+use crate::bbox_walker::walk_clamped;
 use crate::traits::{DimBounds, Overlaps};
 
+#[cfg(feature = "proptest")]
+pub use arbitrary::{bbox_strategy, BBoxParams};
+pub use axis_range::AxisRange;
+pub use builder::BBoxBuilder;
+pub use corner_iter::CornerIter;
+pub use dyn_bbox::DynBBox;
+pub use parse::ParseBBoxError;
+pub use periodic::PeriodicBBox;
+#[cfg(feature = "rand")]
+pub use sample::{SampleScalar, UniformBBox};
+pub use wrap::WrapScalar;
+
 type BBoxElement<N> = (Bound<N>, Bound<N>);
+type PointRange<N, const D: usize> = (Bound<Point<N, D>>, Bound<Point<N, D>>);
 
 /// Generic Axis Aligned Bounding Box
 /// Supports all kinds of bounds, independently on each axis
@@ -27,7 +55,44 @@ pub struct BBox<N: Scalar, const D: usize> {
 }
 
 impl<N: Scalar, const D: usize> BBox<N, D> {
-    /// Builds a bounding box from two unordered points
+    /// Builds a bounding box unbounded on every axis, holding every point. Same as [`BBox::default`].
+    /// Identity element for intersection.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::{BBox, Holds};
+    ///
+    /// assert!(BBox::universe().holds(&point![i32::MIN, i32::MAX]));
+    /// ```
+    #[inline]
+    pub fn universe() -> BBox<N, D>
+    where
+        N: Copy
+    {
+        BBox::default()
+    }
+
+    /// Builds a canonical empty bounding box, going from zero to zero excluded on every axis.
+    /// Identity element for union, intersection with it is always empty.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::{BBox, Holds, IsRangeEmpty};
+    ///
+    /// assert!(BBox::<i32, 2>::empty().is_range_empty());
+    /// assert!(!BBox::empty().holds(&point![0, 0]));
+    /// ```
+    pub fn empty() -> BBox<N, D>
+    where
+        N: Copy + Zero
+    {
+        BBox::from([(Excluded(N::zero()), Excluded(N::zero())); D])
+    }
+
+    /// Builds a bounding box from two unordered points.
+    /// If a coordinate is NaN on one point, the other point's coordinate is used on that axis.
     ///
     /// # Example
     /// ```
@@ -45,13 +110,13 @@ impl<N: Scalar, const D: usize> BBox<N, D> {
     /// ```
     pub fn from_points(a: &Point<N, D>, b: &Point<N, D>) -> BBox<N, D>
     where
-        N: Copy + Ord
+        N: Copy + PartialOrd
     {
         let mut ranges = [(Unbounded, Unbounded); D];
 
         for (idx, range) in ranges.iter_mut().enumerate() {
-            range.0 = Included(*min(unsafe { a.get_unchecked(idx) }, unsafe { b.get_unchecked(idx) }));
-            range.1 = Excluded(*max(unsafe { a.get_unchecked(idx) }, unsafe { b.get_unchecked(idx) }));
+            range.0 = Included(partial_min(unsafe { *a.get_unchecked(idx) }, unsafe { *b.get_unchecked(idx) }));
+            range.1 = Excluded(partial_max(unsafe { *a.get_unchecked(idx) }, unsafe { *b.get_unchecked(idx) }));
         }
 
         BBox {
@@ -77,12 +142,13 @@ impl<N: Scalar, const D: usize> BBox<N, D> {
     /// ```
     pub fn from_anchor_size(anchor: &Point<N, D>, size: &SVector<N, D>) -> BBox<N, D>
     where
-        N: ClosedAdd + Copy + Ord
+        N: ClosedAdd + Copy + PartialOrd
     {
         BBox::from_points(anchor, &(anchor + size))
     }
 
-    /// Builds an including bounding box from two unordered points
+    /// Builds an including bounding box from two unordered points.
+    /// If a coordinate is NaN on one point, the other point's coordinate is used on that axis.
     ///
     /// # Example
     /// ```
@@ -100,13 +166,13 @@ impl<N: Scalar, const D: usize> BBox<N, D> {
     /// ```
     pub fn from_points_included(a: &Point<N, D>, b: &Point<N, D>) -> BBox<N, D>
     where
-        N: Copy + Ord
+        N: Copy + PartialOrd
     {
         let mut ranges = [(Unbounded, Unbounded); D];
 
         for (idx, range) in ranges.iter_mut().enumerate() {
-            range.0 = Included(*min(unsafe { a.get_unchecked(idx) }, unsafe { b.get_unchecked(idx) }));
-            range.1 = Included(*max(unsafe { a.get_unchecked(idx) }, unsafe { b.get_unchecked(idx) }));
+            range.0 = Included(partial_min(unsafe { *a.get_unchecked(idx) }, unsafe { *b.get_unchecked(idx) }));
+            range.1 = Included(partial_max(unsafe { *a.get_unchecked(idx) }, unsafe { *b.get_unchecked(idx) }));
         }
 
         BBox {
@@ -132,414 +198,3315 @@ impl<N: Scalar, const D: usize> BBox<N, D> {
     /// ```
     pub fn from_anchor_size_included(anchor: &Point<N, D>, size: &SVector<N, D>) -> BBox<N, D>
     where
-        N: ClosedAdd + Copy + Ord
+        N: ClosedAdd + Copy + PartialOrd
     {
         BBox::from_points_included(anchor, &(anchor + size))
     }
 
-    /// Returns a reference to an internal range, without doing bounds checking.
-    ///
-    /// # Safety
-    /// Calling this method with an out-of-bounds index is *[undefined behavior]*
-    /// even if the resulting reference is not used.
+    /// Builds an including bounding box from its center and half extents.
+    /// Negative half extents produce an empty bbox on their axis.
     ///
     /// # Example
     /// ```
-    /// use std::ops::Bound::{Excluded, Included};
-    /// use nalgebra::point;
+    /// use std::ops::Bound::Included;
+    /// use nalgebra::{point, vector};
     /// use pythagore::BBox;
     ///
-    /// let bbox = BBox::from(point![1, 2]..point![3, 4]);
-    ///
-    /// unsafe {
-    ///     assert_eq!(bbox.get_unchecked(0), &(Included(1), Excluded(3)));
-    /// }
+    /// assert_eq!(
+    ///     BBox::from_center_half_extents(&point![2, 2], &vector![1, 2]),
+    ///     BBox::from([
+    ///        (Included(1), Included(3)),
+    ///        (Included(0), Included(4)),
+    ///     ])
+    /// );
     /// ```
-    #[inline]
-    pub unsafe fn get_unchecked(&self, idx: usize) -> &BBoxElement<N> {
-        self.ranges.get_unchecked(idx)
+    pub fn from_center_half_extents(center: &Point<N, D>, half: &SVector<N, D>) -> BBox<N, D>
+    where
+        N: ClosedAdd + ClosedSub + Copy
+    {
+        let mut ranges = [(Unbounded, Unbounded); D];
+
+        for (idx, range) in ranges.iter_mut().enumerate() {
+            let c = unsafe { *center.get_unchecked(idx) };
+            let h = unsafe { *half.get_unchecked(idx) };
+
+            *range = (Included(c - h), Included(c + h));
+        }
+
+        BBox { ranges }
     }
 
-    /// Returns a mutable reference to an internal range, without doing bounds checking.
-    ///
-    /// # Safety
-    /// Calling this method with an out-of-bounds index is *[undefined behavior]*
-    /// even if the resulting reference is not used.
+    /// Builds a bounding box from its center and size, excluding its end.
+    /// On integer scalars odd sizes are rounded: the center is the start plus half the size rounded down.
+    /// Negative sizes produce an empty bbox on their axis.
     ///
     /// # Example
     /// ```
-    /// use std::ops::Bound::{Excluded, Included, Unbounded};
-    /// use nalgebra::point;
+    /// use std::ops::Bound::{Excluded, Included};
+    /// use nalgebra::{point, vector};
     /// use pythagore::BBox;
     ///
-    /// let mut bbox = BBox::from(point![1, 2]..point![3, 4]);
-    ///
-    /// unsafe {
-    ///     *bbox.get_unchecked_mut(0) = (Unbounded, Excluded(0))
-    /// }
-    ///
     /// assert_eq!(
-    ///     bbox,
+    ///     BBox::from_center_size(&point![2, 2], &vector![2, 5]),
     ///     BBox::from([
-    ///        (Unbounded, Excluded(0)),
-    ///        (Included(2), Excluded(4)),
+    ///        (Included(1), Excluded(3)),
+    ///        (Included(0), Excluded(5)),
     ///     ])
     /// );
     /// ```
-    #[inline]
-    pub unsafe fn get_unchecked_mut(&mut self, idx: usize) -> &mut BBoxElement<N> {
-        self.ranges.get_unchecked_mut(idx)
-    }
-
-    /// Returns iterator over internal ranges
-    #[inline]
-    pub fn iter(&self) -> Iter<BBoxElement<N>> {
-        self.ranges.iter()
-    }
+    pub fn from_center_size(center: &Point<N, D>, size: &SVector<N, D>) -> BBox<N, D>
+    where
+        N: ClosedAdd + ClosedDiv + ClosedSub + Copy + One
+    {
+        let two = N::one() + N::one();
+        let mut ranges = [(Unbounded, Unbounded); D];
 
-    /// Returns mutable iterator over internal ranges
-    #[inline]
-    pub fn iter_mut(&mut self) -> IterMut<BBoxElement<N>> {
-        self.ranges.iter_mut()
-    }
-}
+        for (idx, range) in ranges.iter_mut().enumerate() {
+            let c = unsafe { *center.get_unchecked(idx) };
+            let s = unsafe { *size.get_unchecked(idx) };
+            let start = c - s / two;
 
-// Utils
-/// Default is a fully unbounded bbox
-///
-/// # Example
-/// ```
-/// use std::ops::Bound::Unbounded;
-/// use pythagore::BBox;
-///
-/// assert_eq!(
-///     BBox::<i32, 2>::default(),
-///     BBox::from([
-///        (Unbounded, Unbounded),
-///        (Unbounded, Unbounded),
-///     ])
-/// );
-/// ```
-impl<N: Copy + Scalar, const D: usize> Default for BBox<N, D> {
-    fn default() -> Self {
-        BBox {
-            ranges: [(Unbounded, Unbounded); D]
+            *range = (Included(start), Excluded(start + s));
         }
-    }
-}
-
-/// Checks if bbox holds given point
-///
-/// # Example
-/// ```
-/// use nalgebra::point;
-/// use pythagore::{BBox, Holds};
-///
-/// assert!(BBox::from(point![0, 0]..point![5, 5]).holds(&point![2, 2]));
-/// ```
-impl<N: Scalar + PartialOrd, const D: usize> Holds<Point<N, D>> for BBox<N, D> {
-    fn holds(&self, object: &Point<N, D>) -> bool {
-        self.ranges.iter().enumerate()
-            .all(|(idx, range)| range.holds(unsafe { object.get_unchecked(idx) }))
-    }
-}
 
-/// Returns true if bounding box cannot hold any point
-///
-/// # Example
-/// ```
-/// use nalgebra::point;
-/// use pythagore::{BBox, IsRangeEmpty};
-///
-/// assert!(BBox::from(point![5, 5]..point![0, 0]).is_range_empty());
-/// ```
-impl<N: Scalar + PartialOrd, const D: usize> IsRangeEmpty for BBox<N, D> {
-    fn is_range_empty(&self) -> bool {
-        self.ranges.iter().any(|range| range.is_range_empty())
-    }
-}
-
-impl<N: Copy + Scalar, const D: usize> DimBounds<N, D> for BBox<N, D> {
-    type Output = (Bound<N>, Bound<N>);
-
-    unsafe fn get_bounds_unchecked(&self, idx: usize) -> Self::Output {
-        *self.ranges.get_unchecked(idx)
+        BBox { ranges }
     }
-}
 
-impl<N: Copy + Scalar + Zero, const D: usize> PointBounds<N, D> for BBox<N, D> {
-    fn start_point(&self) -> Option<Point<N, D>> {
-        let mut point = Point::<N, D>::default();
+    /// Returns center and half extents of bbox, computed from its bounds whatever their kind.
+    /// On integer scalars odd lengths are rounded down, for both center and half extents.
+    /// Returns None if bbox is unbounded on any axis.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::{point, vector};
+    /// use pythagore::BBox;
+    ///
+    /// assert_eq!(
+    ///     BBox::from(point![0, 0]..=point![4, 5]).center_and_half_extents(),
+    ///     Some((point![2, 2], vector![2, 2]))
+    /// );
+    /// ```
+    pub fn center_and_half_extents(&self) -> Option<(Point<N, D>, SVector<N, D>)>
+    where
+        N: ClosedAdd + ClosedDiv + ClosedSub + Copy + One + Zero
+    {
+        let two = N::one() + N::one();
+        let mut center = Point::<N, D>::default();
+        let mut half = SVector::<N, D>::zeros();
 
         for (idx, range) in self.ranges.iter().enumerate() {
-            if let Included(x) | Excluded(x) = range.0 {
-                unsafe { *point.get_unchecked_mut(idx) = x };
-            } else {
-                return None
+            match range {
+                (Included(start) | Excluded(start), Included(end) | Excluded(end)) => {
+                    let h = (*end - *start) / two;
+
+                    unsafe { *center.get_unchecked_mut(idx) = *start + h };
+                    unsafe { *half.get_unchecked_mut(idx) = h };
+                },
+                _ => return None,
             }
         }
 
-        Some(point)
+        Some((center, half))
     }
 
-    fn end_point(&self) -> Option<Point<N, D>> {
-        let mut point = Point::<N, D>::default();
+    /// Builds the smallest including bounding box holding all given points, None if there is no point.
+    /// BBox does not implement FromIterator, as there is no meaningful bbox for an empty set of points.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// assert_eq!(
+    ///     BBox::bounding(&[point![1, 4], point![3, 2], point![2, 5]]),
+    ///     Some(BBox::from(point![1, 2]..=point![3, 5]))
+    /// );
+    /// assert_eq!(BBox::<i32, 2>::bounding(&[]), None);
+    /// ```
+    pub fn bounding(points: &[Point<N, D>]) -> Option<BBox<N, D>>
+    where
+        N: Copy + PartialOrd
+    {
+        let mut builder = BBoxBuilder::new();
 
-        for (idx, range) in self.ranges.iter().enumerate() {
-            if let Included(x) | Excluded(x) = range.1 {
-                unsafe { *point.get_unchecked_mut(idx) = x };
-            } else {
-                return None
-            }
+        for point in points {
+            builder.add_point(point);
         }
 
-        Some(point)
+        builder.build()
     }
-}
 
-impl<N: ClosedAdd + ClosedSub + Copy + One + Scalar + Zero, const D: usize> Walkable<N, D> for BBox<N, D> {
-    fn first_point(&self) -> Option<Point<N, D>> {
-        let mut point = Point::<N, D>::default();
+    /// Returns the smallest bounding box holding both this bbox and the given point.
+    /// Bounds moved to reach the point become included and unbounded sides stay unbounded.
+    /// An empty bbox (even on a single axis) becomes the bbox holding only the point.
+    ///
+    /// # Example
+    /// ```
+    /// use std::ops::Bound::{Excluded, Included};
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// assert_eq!(
+    ///     BBox::from(point![0, 0]..point![5, 5]).include(&point![2, 8]),
+    ///     BBox::from([
+    ///        (Included(0), Excluded(5)),
+    ///        (Included(0), Included(8)),
+    ///     ])
+    /// );
+    /// ```
+    pub fn include(&self, point: impl IntoPoint<N, D>) -> BBox<N, D>
+    where
+        N: Copy + PartialOrd
+    {
+        let point = point.into_point();
+        let mut ranges = self.ranges;
+        let empty = self.is_range_empty();
 
-        for (idx, range) in self.ranges.iter().enumerate() {
-            match range.0 {
-                Included(x) => unsafe { *point.get_unchecked_mut(idx) = x },
-                Excluded(x) => unsafe { *point.get_unchecked_mut(idx) = x + N::one() },
-                Unbounded => return None,
-            }
-        }
+        for (idx, range) in ranges.iter_mut().enumerate() {
+            let x = unsafe { *point.get_unchecked(idx) };
 
-        Some(point)
-    }
+            if empty {
+                *range = (Included(x), Included(x));
+                continue;
+            }
 
-    fn last_point(&self) -> Option<Point<N, D>> {
-        let mut point = Point::<N, D>::default();
+            match range.0 {
+                Included(s) if x < s => range.0 = Included(x),
+                Excluded(s) if x <= s => range.0 = Included(x),
+                _ => (),
+            }
 
-        for (idx, range) in self.ranges.iter().enumerate() {
             match range.1 {
-                Included(x) => unsafe { *point.get_unchecked_mut(idx) = x },
-                Excluded(x) => unsafe { *point.get_unchecked_mut(idx) = x - N::one() },
-                Unbounded => return None,
+                Included(e) if x > e => range.1 = Included(x),
+                Excluded(e) if x >= e => range.1 = Included(x),
+                _ => (),
             }
         }
 
-        Some(point)
+        BBox { ranges }
     }
-}
-
-impl<N: Copy + PartialOrd + Scalar, const D: usize> Intersection for BBox<N, D> {
-    type Output = BBox<N, D>;
-
-    fn intersection(&self, rhs: &Self) -> Self::Output {
-        let mut ranges = [(Unbounded, Unbounded); D];
 
-        for (idx, range) in ranges.iter_mut().enumerate() {
-            let lhs = unsafe { self.get_unchecked(idx) };
-            let rhs = unsafe { rhs.get_unchecked(idx) };
-
-            range.0 = max_bound(lhs.0, rhs.0);
-            range.1 = min_bound(lhs.1, rhs.1);
+    /// Grows bbox to hold all given points, following the same rules as [`BBox::include`]
+    ///
+    /// # Example
+    /// ```
+    /// use std::ops::Bound::{Excluded, Included};
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// let mut bbox = BBox::from(point![0, 0]..point![5, 5]);
+    /// bbox.extend_with([point![-1, 2], point![2, 7]]);
+    ///
+    /// assert_eq!(
+    ///     bbox,
+    ///     BBox::from([
+    ///        (Included(-1), Excluded(5)),
+    ///        (Included(0), Included(7)),
+    ///     ])
+    /// );
+    /// ```
+    pub fn extend_with<I>(&mut self, points: I)
+    where
+        I: IntoIterator<Item = Point<N, D>>,
+        N: Copy + PartialOrd
+    {
+        for point in points {
+            *self = self.include(point);
         }
-
-        BBox::from(ranges)
     }
-}
 
-impl<N: Copy + PartialOrd + Scalar, const D: usize> Intersection<Range<Point<N, D>>> for BBox<N, D> {
-    type Output = BBox<N, D>;
-
-    fn intersection(&self, rhs: &Range<Point<N, D>>) -> Self::Output {
-        let mut ranges = [(Unbounded, Unbounded); D];
+    /// Checks if bbox holds all given points, stopping at the first point outside
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// let bbox = BBox::from(point![0, 0]..point![5, 5]);
+    ///
+    /// assert!(bbox.holds_all([point![1, 1], point![4, 2]]));
+    /// assert!(!bbox.holds_all([point![1, 1], point![6, 2]]));
+    /// ```
+    pub fn holds_all<I>(&self, points: I) -> bool
+    where
+        N: PartialOrd,
+        I: IntoIterator<Item = Point<N, D>>,
+    {
+        points.into_iter().all(|pt| self.holds(&pt))
+    }
 
-        for (idx, range) in ranges.iter_mut().enumerate() {
-            let lhs = unsafe { self.get_unchecked(idx) };
+    /// Checks if bbox holds at least one of given points, stopping at the first point inside
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// let bbox = BBox::from(point![0, 0]..point![5, 5]);
+    ///
+    /// assert!(bbox.holds_any([point![-1, 1], point![4, 2]]));
+    /// assert!(!bbox.holds_any([point![-1, 1], point![6, 2]]));
+    /// ```
+    pub fn holds_any<I>(&self, points: I) -> bool
+    where
+        N: PartialOrd,
+        I: IntoIterator<Item = Point<N, D>>,
+    {
+        points.into_iter().any(|pt| self.holds(&pt))
+    }
 
-            range.0 = max_bound(lhs.0, Included(unsafe { *rhs.start.get_unchecked(idx) }));
-            range.1 = min_bound(lhs.1, Excluded(unsafe { *rhs.end.get_unchecked(idx) }));
+    /// Checks which of given points are held by bbox, writing results into out (cleared first).
+    /// Points are tested axis by axis over the whole slice, which vectorizes better than calling
+    /// [`Holds::holds`] on each point.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// let bbox = BBox::from(point![0, 0]..point![5, 5]);
+    /// let mut out = Vec::new();
+    ///
+    /// bbox.holds_many(&[point![1, 1], point![6, 2], point![4, 2]], &mut out);
+    ///
+    /// assert_eq!(out, vec![true, false, true]);
+    /// ```
+    pub fn holds_many(&self, points: &[Point<N, D>], out: &mut Vec<bool>)
+    where
+        N: Copy + PartialOrd
+    {
+        out.clear();
+        out.resize(points.len(), true);
+
+        for (idx, range) in self.ranges.iter().enumerate() {
+            let coords = points.iter().map(|pt| unsafe { *pt.get_unchecked(idx) });
+
+            match range.0 {
+                Included(start) => out.iter_mut().zip(coords.clone()).for_each(|(res, x)| *res &= start <= x),
+                Excluded(start) => out.iter_mut().zip(coords.clone()).for_each(|(res, x)| *res &= start < x),
+                Unbounded => (),
+            }
+
+            match range.1 {
+                Included(end) => out.iter_mut().zip(coords).for_each(|(res, x)| *res &= x <= end),
+                Excluded(end) => out.iter_mut().zip(coords).for_each(|(res, x)| *res &= x < end),
+                Unbounded => (),
+            }
         }
+    }
 
-        BBox::from(ranges)
+    /// Checks which of given points are held by bbox, see [`BBox::holds_many`]
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// let bbox = BBox::from(point![0, 0]..point![5, 5]);
+    ///
+    /// assert_eq!(bbox.holds_mask(&[point![1, 1], point![6, 2]]), vec![true, false]);
+    /// ```
+    pub fn holds_mask(&self, points: &[Point<N, D>]) -> Vec<bool>
+    where
+        N: Copy + PartialOrd
+    {
+        let mut out = Vec::with_capacity(points.len());
+        self.holds_many(points, &mut out);
+        out
     }
-}
 
-impl<N: Copy + PartialOrd + Scalar, const D: usize> Intersection<RangeFrom<Point<N, D>>> for BBox<N, D> {
-    type Output = BBox<N, D>;
+    /// Splits given points into points held by the bbox, and points outside of it
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// let bbox = BBox::from(point![0, 0]..point![5, 5]);
+    ///
+    /// assert_eq!(
+    ///     bbox.partition_points([point![1, 1], point![6, 2], point![4, 2]]),
+    ///     (vec![point![1, 1], point![4, 2]], vec![point![6, 2]])
+    /// );
+    /// ```
+    pub fn partition_points<I>(&self, points: I) -> (Vec<Point<N, D>>, Vec<Point<N, D>>)
+    where
+        N: PartialOrd,
+        I: IntoIterator<Item = Point<N, D>>,
+    {
+        points.into_iter().partition(|pt| self.holds(pt))
+    }
 
-    fn intersection(&self, rhs: &RangeFrom<Point<N, D>>) -> Self::Output {
-        let mut ranges = [(Unbounded, Unbounded); D];
+    /// Cuts the bbox in two along the given axis, at the given coordinate.
+    /// The first box holds points strictly before `at` (its end bound becomes `Excluded(at)`),
+    /// the second one holds points from `at` (its start bound becomes `Included(at)`).
+    ///
+    /// # Panics
+    /// Panics if axis is out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use std::ops::Bound::{Excluded, Included};
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// let (left, right) = BBox::from(point![0, 0]..point![5, 5]).split_at(0, 2);
+    ///
+    /// assert_eq!(left, BBox::from(point![0, 0]..point![2, 5]));
+    /// assert_eq!(right, BBox::from(point![2, 0]..point![5, 5]));
+    /// ```
+    pub fn split_at(&self, axis: usize, at: N) -> (BBox<N, D>, BBox<N, D>)
+    where
+        N: Copy + PartialOrd
+    {
+        let mut left = *self;
+        let mut right = *self;
 
-        for (idx, range) in ranges.iter_mut().enumerate() {
-            let lhs = unsafe { self.get_unchecked(idx) };
+        left[axis].1 = min_bound(self[axis].1, Excluded(at));
+        right[axis].0 = max_bound(self[axis].0, Included(at));
 
-            range.0 = max_bound(lhs.0, Included(unsafe { *rhs.start.get_unchecked(idx) }));
-            range.1 = lhs.1;
+        (left, right)
+    }
+
+    /// Checks if bbox holds every point of other. Empty boxes are held by any bbox.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// let bbox = BBox::from(point![0, 0]..point![5, 5]);
+    ///
+    /// assert!(bbox.contains_bbox(&BBox::from(point![1, 1]..point![5, 5])));
+    /// assert!(!bbox.contains_bbox(&BBox::from(point![1, 1]..=point![5, 5])));
+    /// ```
+    pub fn contains_bbox(&self, other: impl IntoBBox<N, D>) -> bool
+    where
+        N: Copy + PartialOrd
+    {
+        let other = other.into_bbox();
+
+        other.is_range_empty() || self.ranges.iter().zip(other.ranges.iter())
+            .all(|(range, other)| min_start_bound(range.0, other.0) == range.0 && max_end_bound(range.1, other.1) == range.1)
+    }
+
+    /// Computes the smallest bbox holding both bbox and other. Empty boxes are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// assert_eq!(
+    ///     BBox::from(point![0, 0]..point![2, 2]).union(&BBox::from(point![1, 3]..point![4, 5])),
+    ///     BBox::from(point![0, 0]..point![4, 5])
+    /// );
+    /// ```
+    #[inline]
+    pub fn union(&self, other: impl IntoBBox<N, D>) -> BBox<N, D>
+    where
+        N: Copy + PartialOrd
+    {
+        let mut result = *self;
+        result.union_assign(other);
+        result
+    }
+
+    /// Grows bbox to hold other, in place. Empty boxes are ignored.
+    pub fn union_assign(&mut self, other: impl IntoBBox<N, D>)
+    where
+        N: Copy + PartialOrd
+    {
+        let other = other.into_bbox();
+
+        if other.is_range_empty() {
+            return;
         }
 
-        BBox::from(ranges)
+        if self.is_range_empty() {
+            *self = other;
+            return;
+        }
+
+        for (range, other) in self.ranges.iter_mut().zip(other.ranges.iter()) {
+            range.0 = min_start_bound(range.0, other.0);
+            range.1 = max_end_bound(range.1, other.1);
+        }
     }
-}
 
-impl<N: Copy + Scalar, const D: usize> Intersection<RangeFull> for BBox<N, D> {
-    type Output = BBox<N, D>;
+    /// Returns product of bbox lengths on each axis, None if unbounded, zero if empty.
+    /// Bound kinds are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// assert_eq!(BBox::from(point![0, 1, 2]..point![2, 4, 6]).volume(), Some(24));
+    /// ```
+    pub fn volume(&self) -> Option<N>
+    where
+        N: ClosedMul + ClosedSub + Copy + One + PartialOrd + Zero
+    {
+        let mut volume = N::one();
 
-    #[inline]
-    fn intersection(&self, _: &RangeFull) -> Self::Output {
-        *self
+        for idx in 0..D {
+            volume *= self.axis_length(idx)?;
+        }
+
+        if self.is_range_empty() { Some(N::zero()) } else { Some(volume) }
     }
-}
 
-impl<N: Copy + PartialOrd + Scalar, const D: usize> Intersection<RangeInclusive<Point<N, D>>> for BBox<N, D> {
-    type Output = BBox<N, D>;
+    /// Computes how much the volume of bbox would grow to hold other, None if either is unbounded.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// let bbox = BBox::from(point![0, 0]..point![2, 2]);
+    ///
+    /// assert_eq!(bbox.enlargement_for(&BBox::from(point![1, 1]..point![2, 2])), Some(0));
+    /// assert_eq!(bbox.enlargement_for(&BBox::from(point![2, 0]..point![3, 2])), Some(2));
+    /// ```
+    pub fn enlargement_for(&self, other: &BBox<N, D>) -> Option<N>
+    where
+        N: ClosedMul + ClosedSub + Copy + One + PartialOrd + Zero
+    {
+        let volume = self.volume()?;
+        other.volume()?;
 
-    fn intersection(&self, rhs: &RangeInclusive<Point<N, D>>) -> Self::Output {
-        let mut ranges = [(Unbounded, Unbounded); D];
+        Some(self.union(other).volume()? - volume)
+    }
 
-        for (idx, range) in ranges.iter_mut().enumerate() {
-            let lhs = unsafe { self.get_unchecked(idx) };
+    /// Restricts bbox to its intersection with rhs, in place
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// let mut bbox = BBox::from(point![0, 0]..point![5, 5]);
+    /// bbox.intersect_assign(&BBox::from(point![2, -2]..));
+    ///
+    /// assert_eq!(bbox, BBox::from(point![2, 0]..point![5, 5]));
+    /// ```
+    pub fn intersect_assign(&mut self, rhs: impl IntoBBox<N, D>)
+    where
+        N: Copy + PartialOrd
+    {
+        let rhs = rhs.into_bbox();
 
-            range.0 = max_bound(lhs.0, Included(unsafe { *rhs.start().get_unchecked(idx) }));
-            range.1 = min_bound(lhs.1, Included(unsafe { *rhs.end().get_unchecked(idx) }));
+        for (range, rhs) in self.ranges.iter_mut().zip(rhs.ranges.iter()) {
+            range.0 = max_bound(range.0, rhs.0);
+            range.1 = min_bound(range.1, rhs.1);
         }
+    }
 
-        BBox::from(ranges)
+    /// Checks if bbox and rhs have a non-empty intersection, stopping on the first disjoint axis.
+    /// Accepts any type convertible into a bbox, see [`IntoBBox`].
+    /// Same as `!self.intersection(rhs).is_range_empty()`, without building the intersection.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// let bbox = BBox::from(point![0, 0]..point![5, 5]);
+    ///
+    /// assert!(bbox.intersects(point![4, 4]..));
+    /// assert!(!bbox.intersects(&BBox::from(point![5, 0]..)));
+    /// ```
+    pub fn intersects(&self, rhs: impl IntoBBox<N, D>) -> bool
+    where
+        N: Copy + PartialOrd
+    {
+        let rhs = rhs.into_bbox();
+
+        self.ranges.iter().zip(rhs.ranges.iter())
+            .all(|(lhs, rhs)| !(max_bound(lhs.0, rhs.0), min_bound(lhs.1, rhs.1)).is_range_empty())
     }
-}
 
-impl<N: Copy + PartialOrd + Scalar, const D: usize> Intersection<RangeTo<Point<N, D>>> for BBox<N, D> {
-    type Output = BBox<N, D>;
+    /// Builds a walker over the part of bbox held by clamp, see [`walk_clamped`](crate::bbox_walker::walk_clamped).
+    /// Returns None if that part is unbounded or empty.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// let world = BBox::from(point![0, 0]..);
+    /// let viewport = BBox::from(point![-2, -2]..=point![2, 2]);
+    ///
+    /// assert_eq!(world.walk_within(&viewport).unwrap().iter().count(), 9);
+    /// ```
+    #[inline]
+    pub fn walk_within(&self, clamp: impl IntoBBox<N, D>) -> Option<BBoxWalker<N, D>>
+    where
+        N: CheckedAdd + CheckedSub + ClosedAdd + ClosedSub + Copy + One + PartialOrd + Zero
+    {
+        walk_clamped(self, &clamp.into_bbox())
+    }
 
-    fn intersection(&self, rhs: &RangeTo<Point<N, D>>) -> Self::Output {
-        let mut ranges = [(Unbounded, Unbounded); D];
+    /// Computes the part of bbox not held by other, as up to 2·D disjoint boxes.
+    /// Their union with the intersection of both boxes is exactly this bbox.
+    ///
+    /// # Example
+    /// ```
+    /// use std::ops::Bound::{Excluded, Included};
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// let parts = BBox::from(point![0, 0]..point![5, 5]).difference(&BBox::from(point![2, 0]..point![5, 5]));
+    ///
+    /// assert_eq!(parts, vec![BBox::from(point![0, 0]..point![2, 5])]);
+    /// ```
+    pub fn difference(&self, other: &BBox<N, D>) -> Vec<BBox<N, D>>
+    where
+        N: Copy + PartialOrd
+    {
+        let inter = self.intersection(other);
 
-        for (idx, range) in ranges.iter_mut().enumerate() {
-            let lhs = unsafe { self.get_unchecked(idx) };
+        if inter.is_range_empty() {
+            return if self.is_range_empty() { Vec::new() } else { vec![*self] };
+        }
 
-            range.0 = lhs.0;
-            range.1 = min_bound(lhs.1, Excluded(unsafe { *rhs.end.get_unchecked(idx) }));
+        let mut parts = Vec::new();
+        let mut working = *self;
+
+        for (idx, range) in inter.ranges.iter().enumerate() {
+            let before = match range.0 {
+                Included(s) => Some(Excluded(s)),
+                Excluded(s) => Some(Included(s)),
+                Unbounded => None,
+            };
+
+            if let Some(end) = before {
+                let mut part = working;
+                part.ranges[idx].1 = min_bound(part.ranges[idx].1, end);
+
+                if !part.is_range_empty() {
+                    parts.push(part);
+                }
+            }
+
+            let after = match range.1 {
+                Included(e) => Some(Excluded(e)),
+                Excluded(e) => Some(Included(e)),
+                Unbounded => None,
+            };
+
+            if let Some(start) = after {
+                let mut part = working;
+                part.ranges[idx].0 = max_bound(part.ranges[idx].0, start);
+
+                if !part.is_range_empty() {
+                    parts.push(part);
+                }
+            }
+
+            working.ranges[idx] = *range;
         }
 
-        BBox::from(ranges)
+        parts
     }
-}
 
-impl<N: Copy + PartialOrd + Scalar, const D: usize> Intersection<RangeToInclusive<Point<N, D>>> for BBox<N, D> {
-    type Output = BBox<N, D>;
+    /// Builds a bbox by mapping each bound value with f, keeping bound kinds. f should be monotonic
+    /// (non-decreasing), otherwise starts and ends may cross over.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// let millimeters = BBox::from(point![0, 250]..point![1500, 3000]);
+    ///
+    /// assert_eq!(
+    ///     millimeters.map_scalar(|x| x as f32 / 1000.0),
+    ///     BBox::from(point![0.0, 0.25]..point![1.5, 3.0])
+    /// );
+    /// ```
+    pub fn map_scalar<M: Copy + Scalar>(&self, f: impl Fn(N) -> M) -> BBox<M, D>
+    where
+        N: Copy
+    {
+        let map = |bound: Bound<N>| match bound {
+            Included(x) => Included(f(x)),
+            Excluded(x) => Excluded(f(x)),
+            Unbounded => Unbounded,
+        };
 
-    fn intersection(&self, rhs: &RangeToInclusive<Point<N, D>>) -> Self::Output {
         let mut ranges = [(Unbounded, Unbounded); D];
 
-        for (idx, range) in ranges.iter_mut().enumerate() {
-            let lhs = unsafe { self.get_unchecked(idx) };
+        for (range, src) in ranges.iter_mut().zip(self.ranges.iter()) {
+            *range = (map(src.0), map(src.1));
+        }
+
+        BBox::from(ranges)
+    }
+
+    /// Builds a bbox from the given axes of this bbox, in the given order.
+    ///
+    /// # Panics
+    /// Panics if an axis is out of bounds or selected twice.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::{BBox, Holds};
+    ///
+    /// let bbox = BBox::from(point![0, 0, 0]..point![5, 5, 5]);
+    ///
+    /// assert_eq!(bbox.project([0, 2]), BBox::from(point![0, 0]..point![5, 5]));
+    /// assert!(bbox.project([0, 1]).holds(&point![2, 3]));
+    /// ```
+    pub fn project<const K: usize>(&self, axes: [usize; K]) -> BBox<N, K>
+    where
+        N: Copy
+    {
+        let mut ranges = [(Unbounded, Unbounded); K];
+
+        for (idx, (range, axis)) in ranges.iter_mut().zip(axes).enumerate() {
+            assert!(axis < D, "Axis {axis} is out of bounds for a {D} dimensions bbox");
+            assert!(!axes[..idx].contains(&axis), "Axis {axis} is selected twice");
+
+            *range = self.ranges[axis];
+        }
+
+        BBox { ranges }
+    }
+
+    /// Returns true if bbox is bounded on every axis
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// assert!(BBox::from(point![0, 0]..point![5, 5]).is_bounded());
+    /// assert!(!BBox::from(point![0, 0]..).is_bounded());
+    /// ```
+    pub fn is_bounded(&self) -> bool {
+        self.bounded_axes().iter().all(|bounded| *bounded)
+    }
+
+    /// Returns true if every bound of bbox is included
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// assert!(BBox::from(point![0, 0]..=point![5, 5]).is_closed());
+    /// assert!(!BBox::from(point![0, 0]..point![5, 5]).is_closed());
+    /// ```
+    pub fn is_closed(&self) -> bool {
+        self.ranges.iter()
+            .all(|range| matches!(range, (Included(_), Included(_))))
+    }
+
+    /// Returns, for each axis, true if bbox is bounded on both sides of that axis
+    ///
+    /// # Example
+    /// ```
+    /// use std::ops::Bound::{Excluded, Included, Unbounded};
+    /// use pythagore::BBox;
+    ///
+    /// let bbox = BBox::from([(Included(0), Excluded(5)), (Unbounded, Included(5))]);
+    ///
+    /// assert_eq!(bbox.bounded_axes(), [true, false]);
+    /// ```
+    pub fn bounded_axes(&self) -> [bool; D] {
+        let mut bounded = [false; D];
+
+        for (res, range) in bounded.iter_mut().zip(self.ranges.iter()) {
+            *res = !matches!(range, (Unbounded, _) | (_, Unbounded));
+        }
+
+        bounded
+    }
+
+    /// Converts excluded bounds to included ones, by shifting them by one.
+    /// Returns None if bbox is unbounded on any axis, or if a shift overflows, as [`Walkable::first_point`]
+    /// and [`Walkable::last_point`] do.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// assert_eq!(
+    ///     BBox::from(point![0, 0]..point![5, 5]).close(),
+    ///     Some(BBox::from(point![0, 0]..=point![4, 4]))
+    /// );
+    /// assert_eq!(BBox::from(point![0, 0]..).close(), None);
+    /// ```
+    pub fn close(&self) -> Option<BBox<N, D>>
+    where
+        N: CheckedAdd + CheckedSub + Copy + One
+    {
+        let mut bbox = *self;
+
+        for range in bbox.ranges.iter_mut() {
+            *range = (lattice_start(range.0)?, lattice_end(range.1)?);
+
+            if matches!(range, (Unbounded, _) | (_, Unbounded)) {
+                return None;
+            }
+        }
+
+        Some(bbox)
+    }
+
+    /// Rewrites bounds in the half-open normal form, with included starts and excluded ends, by
+    /// shifting them by one. Unbounded bounds are kept, and all empty boxes give [`BBox::empty`].
+    /// Ends on the scalar maximum cannot be shifted, so they stay included, which is their only
+    /// possible form.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// assert_eq!(
+    ///     BBox::from(point![0, 0]..=point![4, 4]).canonicalize(),
+    ///     BBox::from(point![0, 0]..point![5, 5])
+    /// );
+    /// ```
+    pub fn canonicalize(&self) -> BBox<N, D>
+    where
+        N: PrimInt
+    {
+        let mut bbox = *self;
+
+        for range in bbox.ranges.iter_mut() {
+            // Excluded starts on the maximum are kept, making the box empty
+            if let Excluded(Some(x)) = range.0.map(|x| x.checked_add(&N::one())) {
+                range.0 = Included(x);
+            }
+
+            if let Included(Some(x)) = range.1.map(|x| x.checked_add(&N::one())) {
+                range.1 = Excluded(x);
+            }
+        }
+
+        if bbox.is_range_empty() {
+            BBox::empty()
+        } else {
+            bbox
+        }
+    }
+
+    /// Checks if bbox and other hold the same lattice points, whatever their bound kinds
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// assert!(BBox::from(point![0, 0]..=point![4, 4]).lattice_eq(&BBox::from(point![0, 0]..point![5, 5])));
+    /// ```
+    #[inline]
+    pub fn lattice_eq(&self, other: &BBox<N, D>) -> bool
+    where
+        N: PrimInt
+    {
+        self.canonicalize() == other.canonicalize()
+    }
+
+    /// Returns start point of bbox, using coordinates of the given default point on axes unbounded at start
+    ///
+    /// # Example
+    /// ```
+    /// use std::ops::Bound::{Excluded, Included, Unbounded};
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// let bbox = BBox::from([(Included(1), Excluded(5)), (Unbounded, Excluded(5))]);
+    ///
+    /// assert_eq!(bbox.start_point_or(&point![0, 0]), point![1, 0]);
+    /// ```
+    pub fn start_point_or(&self, default: &Point<N, D>) -> Point<N, D>
+    where
+        N: Copy
+    {
+        let mut point = *default;
+
+        for (idx, range) in self.ranges.iter().enumerate() {
+            if let Included(x) | Excluded(x) = range.0 {
+                unsafe { *point.get_unchecked_mut(idx) = x };
+            }
+        }
+
+        point
+    }
+
+    /// Returns end point of bbox, using coordinates of the given default point on axes unbounded at end
+    ///
+    /// # Example
+    /// ```
+    /// use std::ops::Bound::{Excluded, Included, Unbounded};
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// let bbox = BBox::from([(Included(1), Unbounded), (Unbounded, Excluded(5))]);
+    ///
+    /// assert_eq!(bbox.end_point_or(&point![10, 10]), point![10, 5]);
+    /// ```
+    pub fn end_point_or(&self, default: &Point<N, D>) -> Point<N, D>
+    where
+        N: Copy
+    {
+        let mut point = *default;
+
+        for (idx, range) in self.ranges.iter().enumerate() {
+            if let Included(x) | Excluded(x) = range.1 {
+                unsafe { *point.get_unchecked_mut(idx) = x };
+            }
+        }
+
+        point
+    }
+
+    /// Clips bbox to the given viewport. Same as intersection, the result is bounded if viewport is.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// let viewport = BBox::from(point![0, 0]..point![10, 10]);
+    ///
+    /// assert_eq!(
+    ///     BBox::from(point![5, -5]..).clamped_to(&viewport),
+    ///     BBox::from(point![5, 0]..point![10, 10])
+    /// );
+    /// ```
+    #[inline]
+    pub fn clamped_to(&self, viewport: impl IntoBBox<N, D>) -> BBox<N, D>
+    where
+        N: Copy + PartialOrd
+    {
+        self.intersection(&viewport.into_bbox())
+    }
+
+    /// Returns an iterator over the 2^D corners of bbox, in xy order (see [`CornerIter`]).
+    /// Corners are built from bounds values, whatever their kind. Returns None if bbox is unbounded on any axis.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// let corners: Vec<_> = BBox::from(point![0, 0]..point![5, 5]).corners().unwrap().collect();
+    ///
+    /// assert_eq!(corners, vec![point![0, 0], point![0, 5], point![5, 0], point![5, 5]]);
+    /// ```
+    pub fn corners(&self) -> Option<CornerIter<N, D>>
+    where
+        N: Copy + Zero
+    {
+        Some(CornerIter::new(self.start_point()?, self.end_point()?))
+    }
+
+    /// Returns the corner of bbox nearest to the given point, preferring start values on ties.
+    /// Corners are built from bounds values, whatever their kind. Returns None if bbox is unbounded on any axis.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// let bbox = BBox::from(point![0, 0]..point![5, 5]);
+    ///
+    /// assert_eq!(bbox.nearest_corner_to(&point![4, -2]), Some(point![5, 0]));
+    /// ```
+    pub fn nearest_corner_to(&self, point: &Point<N, D>) -> Option<Point<N, D>>
+    where
+        N: ClosedAdd + ClosedSub + Copy + PartialOrd + Zero
+    {
+        self.pick_corner(|idx, start, end| {
+            let x = unsafe { *point.get_unchecked(idx) };
+            cmp_distances(x, start, end) != Some(Ordering::Greater)
+        })
+    }
+
+    /// Returns the corner of bbox farthest from the given point, preferring end values on ties.
+    /// Corners are built from bounds values, whatever their kind. Returns None if bbox is unbounded on any axis.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// let bbox = BBox::from(point![0, 0]..point![5, 5]);
+    ///
+    /// assert_eq!(bbox.farthest_corner_from(&point![4, -2]), Some(point![0, 5]));
+    /// ```
+    pub fn farthest_corner_from(&self, point: &Point<N, D>) -> Option<Point<N, D>>
+    where
+        N: ClosedAdd + ClosedSub + Copy + PartialOrd + Zero
+    {
+        self.pick_corner(|idx, start, end| {
+            let x = unsafe { *point.get_unchecked(idx) };
+            cmp_distances(x, start, end) == Some(Ordering::Greater)
+        })
+    }
+
+    /// Returns the corner of bbox maximizing the dot product with dir (support function of the bbox),
+    /// using end values on axes where dir is null.
+    /// Corners are built from bounds values, whatever their kind. Returns None if bbox is unbounded on any axis.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::{point, vector};
+    /// use pythagore::BBox;
+    ///
+    /// let bbox = BBox::from(point![0, 0]..point![5, 5]);
+    ///
+    /// assert_eq!(bbox.support_point(&vector![-1, 2]), Some(point![0, 5]));
+    /// ```
+    pub fn support_point(&self, dir: &SVector<N, D>) -> Option<Point<N, D>>
+    where
+        N: Copy + PartialOrd + Zero
+    {
+        self.pick_corner(|idx, _, _| unsafe { *dir.get_unchecked(idx) } < N::zero())
+    }
+
+    /// Builds a corner, using start value on axes where pick_start returns true and end value on others
+    fn pick_corner(&self, pick_start: impl Fn(usize, N, N) -> bool) -> Option<Point<N, D>>
+    where
+        N: Copy + Zero
+    {
+        let mut corner = self.start_point()?;
+        let end = self.end_point()?;
+
+        for (idx, x) in corner.iter_mut().enumerate() {
+            let e = unsafe { *end.get_unchecked(idx) };
+
+            if !pick_start(idx, *x, e) {
+                *x = e;
+            }
+        }
+
+        Some(corner)
+    }
+
+    /// Computes the manhattan distance from the given point to the nearest lattice point held by bbox.
+    /// Returns zero for held points, unbounded sides never contribute to the distance.
+    /// Returns None if the distance does not fit in the scalar type, or if an excluded bound cannot be
+    /// moved to a lattice value (see [`Walkable::first_point`]).
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// let bbox = BBox::from(point![0, 0]..point![5, 5]);
+    ///
+    /// assert_eq!(bbox.manhattan_distance_to_point(&point![2, 2]), Some(0));
+    /// assert_eq!(bbox.manhattan_distance_to_point(&point![7, -1]), Some(4));
+    /// ```
+    pub fn manhattan_distance_to_point(&self, point: &Point<N, D>) -> Option<N>
+    where
+        N: CheckedAdd + CheckedSub + Copy + One + Ord + Zero
+    {
+        let mut distance = N::zero();
+
+        for (range, x) in self.ranges.iter().zip(point.iter()) {
+            let gap = match (lattice_start(range.0)?, lattice_end(range.1)?) {
+                (Included(first), _) if *x < first => first.checked_sub(x)?,
+                (_, Included(last)) if *x > last => x.checked_sub(&last)?,
+                _ => continue,
+            };
+
+            distance = distance.checked_add(&gap)?;
+        }
+
+        Some(distance)
+    }
+
+    /// Computes entry and exit parameters of the given ray through bbox, using the slab method.
+    /// Points of the ray are `origin + t * dir`, only intersections with `t >= 0` are kept, so entry is
+    /// negative when origin is inside bbox. Unbounded sides are infinite slabs, and excluded bounds are
+    /// treated as included ones.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::{point, vector};
+    /// use pythagore::BBox;
+    ///
+    /// let bbox = BBox::from(point![0.0, 0.0]..point![1.0, 1.0]);
+    ///
+    /// assert_eq!(bbox.intersect_ray(&point![-1.0, 0.5], &vector![2.0, 0.0]), Some((0.5, 1.0)));
+    /// assert_eq!(bbox.intersect_ray(&point![-1.0, 2.0], &vector![2.0, 0.0]), None);
+    /// ```
+    pub fn intersect_ray(&self, origin: &Point<N, D>, dir: &SVector<N, D>) -> Option<(N, N)>
+    where
+        N: FloatCore
+    {
+        let (enter, exit) = self.intersect_line(origin, dir)?;
+
+        if exit < N::zero() { None } else { Some((enter, exit)) }
+    }
+
+    /// Computes entry and exit parameters of the given segment through bbox, clamped to [0, 1].
+    /// Points of the segment are `a + t * (b - a)`, with the same rules as [`BBox::intersect_ray`].
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// let bbox = BBox::from(point![0.0, 0.0]..point![1.0, 1.0]);
+    ///
+    /// assert_eq!(bbox.intersect_segment(&point![0.5, 0.5], &point![2.5, 0.5]), Some((0.0, 0.25)));
+    /// assert_eq!(bbox.intersect_segment(&point![1.5, 0.5], &point![2.5, 0.5]), None);
+    /// ```
+    pub fn intersect_segment(&self, a: &Point<N, D>, b: &Point<N, D>) -> Option<(N, N)>
+    where
+        N: ClosedSub + FloatCore
+    {
+        let (enter, exit) = self.intersect_line(a, &(b - a))?;
+
+        if exit < N::zero() || enter > N::one() {
+            None
+        } else {
+            Some((enter.max(N::zero()), exit.min(N::one())))
+        }
+    }
+
+    /// Clips the segment going from a to b to the part inside bbox, using the Liang-Barsky method.
+    /// Returns None if segment misses bbox. Excluded bounds are open, so a segment only touching
+    /// an excluded face misses bbox, and unbounded axes do not clip.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// let bbox = BBox::from(point![0.0, 0.0]..point![2.0, 2.0]);
+    ///
+    /// assert_eq!(
+    ///     bbox.clip_segment(&point![-1.0, 1.0], &point![1.0, 1.0]),
+    ///     Some((point![0.0, 1.0], point![1.0, 1.0]))
+    /// );
+    /// assert_eq!(bbox.clip_segment(&point![2.0, 0.0], &point![2.0, 2.0]), None);
+    /// ```
+    pub fn clip_segment(&self, a: &Point<N, D>, b: &Point<N, D>) -> Option<(Point<N, D>, Point<N, D>)>
+    where
+        N: ClosedAdd + ClosedMul + ClosedSub + FloatCore
+    {
+        let (enter, exit) = self.intersect_segment(a, b)?;
+        let segment = Segment::new(*a, *b);
+        let point_at = |t: N| if t == N::one() { *b } else { segment.point_at(t) };
+
+        let middle = (enter + exit) / (N::one() + N::one());
+
+        // Clipped segment may only lie on excluded faces
+        if self.holds(&point_at(middle)) {
+            Some((point_at(enter), point_at(exit)))
+        } else {
+            None
+        }
+    }
+
+    /// Computes entry and exit parameters of the given line through bbox
+    fn intersect_line(&self, origin: &Point<N, D>, dir: &SVector<N, D>) -> Option<(N, N)>
+    where
+        N: FloatCore
+    {
+        let mut enter = N::neg_infinity();
+        let mut exit = N::infinity();
+
+        for (idx, range) in self.ranges.iter().enumerate() {
+            let o = unsafe { *origin.get_unchecked(idx) };
+            let d = unsafe { *dir.get_unchecked(idx) };
+
+            let start = match range.0 {
+                Included(x) | Excluded(x) => x,
+                Unbounded => N::neg_infinity(),
+            };
+
+            let end = match range.1 {
+                Included(x) | Excluded(x) => x,
+                Unbounded => N::infinity(),
+            };
+
+            if end < start {
+                return None;
+            }
+
+            if d == N::zero() {
+                if o < start || o > end {
+                    return None;
+                }
+
+                continue;
+            }
+
+            let t1 = (start - o) / d;
+            let t2 = (end - o) / d;
+
+            enter = enter.max(t1.min(t2));
+            exit = exit.min(t1.max(t2));
+        }
+
+        if enter > exit { None } else { Some((enter, exit)) }
+    }
+
+    /// Converts bbox back into a range of points. Returns None unless all axes share the same start
+    /// bound kind and the same end bound kind, as such a bbox is not representable by a range of points.
+    ///
+    /// This is also why BBox cannot implement [`core::ops::RangeBounds`]: `start_bound` and `end_bound`
+    /// could not return anything for boxes mixing bound kinds.
+    ///
+    /// # Example
+    /// ```
+    /// use std::ops::Bound::{Excluded, Included, Unbounded};
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// assert_eq!(
+    ///     BBox::from(point![0, 0]..point![5, 5]).try_to_point_range(),
+    ///     Some((Included(point![0, 0]), Excluded(point![5, 5])))
+    /// );
+    /// assert_eq!(
+    ///     BBox::from([(Included(0), Excluded(5)), (Included(0), Included(5))]).try_to_point_range(),
+    ///     None
+    /// );
+    /// ```
+    pub fn try_to_point_range(&self) -> Option<PointRange<N, D>>
+    where
+        N: Copy
+    {
+        Some((
+            self.point_bound(|range| &range.0)?,
+            self.point_bound(|range| &range.1)?,
+        ))
+    }
+
+    /// Merges bounds selected on each axis into a point bound, None if they are not of the same kind
+    fn point_bound(&self, side: impl Fn(&BBoxElement<N>) -> &Bound<N>) -> Option<Bound<Point<N, D>>>
+    where
+        N: Copy
+    {
+        let kind = self.ranges.first().map(|range| core::mem::discriminant(side(range)));
+
+        if self.ranges.iter().any(|range| Some(core::mem::discriminant(side(range))) != kind) {
+            return None;
+        }
+
+        let coords = || Point::from(core::array::from_fn(|idx| match side(&self.ranges[idx]) {
+            Included(x) | Excluded(x) => *x,
+            Unbounded => unreachable!(),
+        }));
+
+        match self.ranges.first().map(&side) {
+            Some(Included(_)) => Some(Included(coords())),
+            Some(Excluded(_)) => Some(Excluded(coords())),
+            _ => Some(Unbounded),
+        }
+    }
+
+    /// Returns the point at given fractions of bbox on each axis: 0 gives the start, 1 the end.
+    /// Uses bounds values whatever their kind. Returns None if bbox is empty, unbounded or has a zero length axis.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::{point, vector};
+    /// use pythagore::BBox;
+    ///
+    /// let bbox = BBox::from(point![0.0, 0.0]..point![10.0, 100.0]);
+    ///
+    /// assert_eq!(bbox.point_at_fraction(&vector![0.3, 0.8]), Some(point![3.0, 80.0]));
+    /// ```
+    pub fn point_at_fraction(&self, fractions: &SVector<N, D>) -> Option<Point<N, D>>
+    where
+        N: FloatCore
+    {
+        if self.is_range_empty() {
+            return None;
+        }
+
+        let mut point = Point::from(*fractions);
+
+        for (idx, x) in point.iter_mut().enumerate() {
+            let (start, len) = self.axis_span(idx)?;
+            *x = start + *x * len;
+        }
+
+        Some(point)
+    }
+
+    /// Returns the fractions of bbox at which given point stands on each axis, inverse of
+    /// [`BBox::point_at_fraction`]. Fractions are outside [0, 1] for points outside bbox.
+    /// Uses bounds values whatever their kind. Returns None if bbox is empty, unbounded or has a zero length axis.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::{point, vector};
+    /// use pythagore::BBox;
+    ///
+    /// let bbox = BBox::from(point![0.0, 0.0]..point![10.0, 100.0]);
+    ///
+    /// assert_eq!(bbox.fraction_of_point(&point![5.0, 150.0]), Some(vector![0.5, 1.5]));
+    /// ```
+    pub fn fraction_of_point(&self, point: &Point<N, D>) -> Option<SVector<N, D>>
+    where
+        N: FloatCore
+    {
+        if self.is_range_empty() {
+            return None;
+        }
+
+        let mut fractions = point.coords;
+
+        for (idx, x) in fractions.iter_mut().enumerate() {
+            let (start, len) = self.axis_span(idx)?;
+            *x = (*x - start) / len;
+        }
+
+        Some(fractions)
+    }
+
+    /// Maps given point from this bbox to the target bbox, by linear interpolation on each axis.
+    /// Uses bounds values whatever their kind. Returns None if a bbox is unbounded or has a zero length axis.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// let data = BBox::from(point![0.0, 0.0]..point![10.0, 100.0]);
+    /// let screen = BBox::from(point![0.0, 480.0]..point![640.0, 0.0]);
+    ///
+    /// assert_eq!(data.map_point_to(&screen, &point![5.0, 25.0]), Some(point![320.0, 360.0]));
+    /// ```
+    pub fn map_point_to(&self, target: &BBox<N, D>, point: &Point<N, D>) -> Option<Point<N, D>>
+    where
+        N: FloatCore
+    {
+        let mut res = *point;
+
+        for (idx, x) in res.iter_mut().enumerate() {
+            let (src_start, src_len) = self.axis_span(idx)?;
+            let (tgt_start, tgt_len) = target.axis_span(idx)?;
+
+            *x = (*x - src_start) / src_len * tgt_len + tgt_start;
+        }
+
+        Some(res)
+    }
+
+    /// Returns per axis scale and offset mapping this bbox to the target bbox
+    fn axis_mappings(&self, target: &BBox<N, D>) -> Option<[(N, N); D]>
+    where
+        N: FloatCore
+    {
+        let mut mappings = [(N::one(), N::zero()); D];
+
+        for (idx, mapping) in mappings.iter_mut().enumerate() {
+            let (src_start, src_len) = self.axis_span(idx)?;
+            let (tgt_start, tgt_len) = target.axis_span(idx)?;
+            let scale = tgt_len / src_len;
+
+            *mapping = (scale, tgt_start - src_start * scale);
+        }
+
+        Some(mappings)
+    }
+
+    /// Returns start and length of bbox along given axis, None if unbounded or of zero length on that axis
+    fn axis_span(&self, axis: usize) -> Option<(N, N)>
+    where
+        N: FloatCore
+    {
+        match self.ranges[axis] {
+            (Included(start) | Excluded(start), Included(end) | Excluded(end)) if end != start => Some((start, end - start)),
+            _ => None,
+        }
+    }
+
+    /// Returns length of bbox along given axis, None if unbounded on that axis
+    fn axis_length(&self, axis: usize) -> Option<N>
+    where
+        N: ClosedSub + Copy
+    {
+        match self.ranges[axis] {
+            (Included(start) | Excluded(start), Included(end) | Excluded(end)) => Some(end - start),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to an internal range, without doing bounds checking.
+    ///
+    /// # Safety
+    /// Calling this method with an out-of-bounds index is *[undefined behavior]*
+    /// even if the resulting reference is not used.
+    ///
+    /// # Example
+    /// ```
+    /// use std::ops::Bound::{Excluded, Included};
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// let bbox = BBox::from(point![1, 2]..point![3, 4]);
+    ///
+    /// unsafe {
+    ///     assert_eq!(bbox.get_unchecked(0), &(Included(1), Excluded(3)));
+    /// }
+    /// ```
+    #[inline]
+    pub unsafe fn get_unchecked(&self, idx: usize) -> &BBoxElement<N> {
+        self.ranges.get_unchecked(idx)
+    }
+
+    /// Returns a mutable reference to an internal range, without doing bounds checking.
+    ///
+    /// # Safety
+    /// Calling this method with an out-of-bounds index is *[undefined behavior]*
+    /// even if the resulting reference is not used.
+    ///
+    /// # Example
+    /// ```
+    /// use std::ops::Bound::{Excluded, Included, Unbounded};
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// let mut bbox = BBox::from(point![1, 2]..point![3, 4]);
+    ///
+    /// unsafe {
+    ///     *bbox.get_unchecked_mut(0) = (Unbounded, Excluded(0))
+    /// }
+    ///
+    /// assert_eq!(
+    ///     bbox,
+    ///     BBox::from([
+    ///        (Unbounded, Excluded(0)),
+    ///        (Included(2), Excluded(4)),
+    ///     ])
+    /// );
+    /// ```
+    #[inline]
+    pub unsafe fn get_unchecked_mut(&mut self, idx: usize) -> &mut BBoxElement<N> {
+        self.ranges.get_unchecked_mut(idx)
+    }
+
+    /// Returns bounds of given axis as a std range, usable with any api accepting [`RangeBounds`].
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// let map = BTreeMap::from([(1, 'a'), (3, 'b'), (5, 'c')]);
+    /// let bbox = BBox::from(point![2, 0]..point![5, 1]);
+    ///
+    /// assert_eq!(map.range(bbox.range_bounds(0)).collect::<Vec<_>>(), vec![(&3, &'b')]);
+    /// ```
+    #[inline]
+    pub fn range_bounds(&self, axis: usize) -> impl RangeBounds<N> + '_ {
+        let range = &self.ranges[axis];
+        (range.0.as_ref(), range.1.as_ref())
+    }
+
+    /// Returns bounds of all axes, by reference
+    ///
+    /// # Example
+    /// ```
+    /// use std::ops::Bound::{Excluded, Included};
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// assert_eq!(
+    ///     BBox::from(point![1, 2]..point![3, 4]).bounds_as_ref(),
+    ///     [(Included(&1), Excluded(&3)), (Included(&2), Excluded(&4))]
+    /// );
+    /// ```
+    pub fn bounds_as_ref(&self) -> [(Bound<&N>, Bound<&N>); D] {
+        core::array::from_fn(|idx| {
+            let range = unsafe { self.ranges.get_unchecked(idx) };
+            (range.0.as_ref(), range.1.as_ref())
+        })
+    }
+
+    /// Checks if given value is held by bbox on given axis
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// let bbox = BBox::from(point![1, 2]..point![3, 4]);
+    ///
+    /// assert!(bbox.contains_on_axis(0, &1));
+    /// assert!(!bbox.contains_on_axis(1, &4));
+    /// ```
+    #[inline]
+    pub fn contains_on_axis(&self, axis: usize, value: &N) -> bool
+    where
+        N: PartialOrd
+    {
+        self.range_bounds(axis).contains(value)
+    }
+
+    /// Returns iterator over internal ranges
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, BBoxElement<N>> {
+        self.ranges.iter()
+    }
+
+    /// Returns mutable iterator over internal ranges
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, BBoxElement<N>> {
+        self.ranges.iter_mut()
+    }
+}
+
+impl<N: Copy + Scalar> BBox<N, 2> {
+    /// Returns bounds on the x axis
+    #[inline]
+    pub fn x_range(&self) -> BBoxElement<N> {
+        self.ranges[0]
+    }
+
+    /// Returns bounds on the y axis
+    #[inline]
+    pub fn y_range(&self) -> BBoxElement<N> {
+        self.ranges[1]
+    }
+
+    /// Sets bounds on the x axis
+    #[inline]
+    pub fn set_x_range(&mut self, range: BBoxElement<N>) {
+        self.ranges[0] = range;
+    }
+
+    /// Sets bounds on the y axis
+    #[inline]
+    pub fn set_y_range(&mut self, range: BBoxElement<N>) {
+        self.ranges[1] = range;
+    }
+
+    /// Returns distance between start and end on the x axis, None if unbounded.
+    /// Bound kinds are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// assert_eq!(BBox::from(point![1, 2]..point![4, 8]).width(), Some(3));
+    /// ```
+    #[inline]
+    pub fn width(&self) -> Option<N>
+    where
+        N: ClosedSub
+    {
+        self.axis_length(0)
+    }
+
+    /// Returns distance between start and end on the y axis, None if unbounded.
+    /// Bound kinds are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// assert_eq!(BBox::from(point![1, 2]..point![4, 8]).height(), Some(6));
+    /// ```
+    #[inline]
+    pub fn height(&self) -> Option<N>
+    where
+        N: ClosedSub
+    {
+        self.axis_length(1)
+    }
+
+    /// Builds a 3D bbox by adding the given bounds as z axis
+    ///
+    /// # Example
+    /// ```
+    /// use std::ops::Bound::Unbounded;
+    /// use nalgebra::point;
+    /// use pythagore::{BBox, Holds};
+    ///
+    /// let bbox = BBox::from(point![0, 0]..point![5, 5]).extrude((Unbounded, Unbounded));
+    ///
+    /// assert!(bbox.holds(&point![2, 3, 42]));
+    /// ```
+    pub fn extrude(&self, z_range: BBoxElement<N>) -> BBox<N, 3> {
+        BBox::from([self.ranges[0], self.ranges[1], z_range])
+    }
+
+    /// Returns the 4 corners of bbox, in the same order as [`BBox::corners`]
+    pub fn corners_array(&self) -> Option<[Point<N, 2>; 4]>
+    where
+        N: Zero
+    {
+        let mut corners = self.corners()?;
+        Some(core::array::from_fn(|_| corners.next().unwrap()))
+    }
+
+    /// Returns homogeneous matrix mapping this bbox to the target bbox, equivalent to [`BBox::map_point_to`]
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// let data = BBox::from(point![0.0, 0.0]..point![10.0, 100.0]);
+    /// let screen = BBox::from(point![0.0, 480.0]..point![640.0, 0.0]);
+    /// let mapping = data.mapping_to(&screen).unwrap();
+    ///
+    /// assert_eq!(mapping.transform_point(&point![5.0, 25.0]), point![320.0, 360.0]);
+    /// ```
+    pub fn mapping_to(&self, target: &BBox<N, 2>) -> Option<Matrix3<N>>
+    where
+        N: FloatCore
+    {
+        let [(sx, tx), (sy, ty)] = self.axis_mappings(target)?;
+
+        Some(Matrix3::new(
+            sx, N::zero(), tx,
+            N::zero(), sy, ty,
+            N::zero(), N::zero(), N::one(),
+        ))
+    }
+}
+
+impl<N: Copy + Scalar> BBox<N, 3> {
+    /// Returns bounds on the x axis
+    #[inline]
+    pub fn x_range(&self) -> BBoxElement<N> {
+        self.ranges[0]
+    }
+
+    /// Returns bounds on the y axis
+    #[inline]
+    pub fn y_range(&self) -> BBoxElement<N> {
+        self.ranges[1]
+    }
+
+    /// Returns bounds on the z axis
+    #[inline]
+    pub fn z_range(&self) -> BBoxElement<N> {
+        self.ranges[2]
+    }
+
+    /// Sets bounds on the x axis
+    #[inline]
+    pub fn set_x_range(&mut self, range: BBoxElement<N>) {
+        self.ranges[0] = range;
+    }
+
+    /// Sets bounds on the y axis
+    #[inline]
+    pub fn set_y_range(&mut self, range: BBoxElement<N>) {
+        self.ranges[1] = range;
+    }
+
+    /// Sets bounds on the z axis
+    #[inline]
+    pub fn set_z_range(&mut self, range: BBoxElement<N>) {
+        self.ranges[2] = range;
+    }
+
+    /// Returns distance between start and end on the x axis, None if unbounded.
+    /// Bound kinds are ignored.
+    #[inline]
+    pub fn width(&self) -> Option<N>
+    where
+        N: ClosedSub
+    {
+        self.axis_length(0)
+    }
+
+    /// Returns distance between start and end on the y axis, None if unbounded.
+    /// Bound kinds are ignored.
+    #[inline]
+    pub fn height(&self) -> Option<N>
+    where
+        N: ClosedSub
+    {
+        self.axis_length(1)
+    }
+
+    /// Returns distance between start and end on the z axis, None if unbounded.
+    /// Bound kinds are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// assert_eq!(BBox::from(point![1, 2, 3]..point![4, 8, 4]).depth(), Some(1));
+    /// ```
+    #[inline]
+    pub fn depth(&self) -> Option<N>
+    where
+        N: ClosedSub
+    {
+        self.axis_length(2)
+    }
+
+    /// Returns the 8 corners of bbox, in the same order as [`BBox::corners`]
+    pub fn corners_array(&self) -> Option<[Point<N, 3>; 8]>
+    where
+        N: Zero
+    {
+        let mut corners = self.corners()?;
+        Some(core::array::from_fn(|_| corners.next().unwrap()))
+    }
+
+    /// Returns homogeneous matrix mapping this bbox to the target bbox, equivalent to [`BBox::map_point_to`]
+    pub fn mapping_to(&self, target: &BBox<N, 3>) -> Option<Matrix4<N>>
+    where
+        N: FloatCore
+    {
+        let [(sx, tx), (sy, ty), (sz, tz)] = self.axis_mappings(target)?;
+
+        Some(Matrix4::new(
+            sx, N::zero(), N::zero(), tx,
+            N::zero(), sy, N::zero(), ty,
+            N::zero(), N::zero(), sz, tz,
+            N::zero(), N::zero(), N::zero(), N::one(),
+        ))
+    }
+}
+
+// Utils
+/// Default is a fully unbounded bbox
+///
+/// # Example
+/// ```
+/// use std::ops::Bound::Unbounded;
+/// use pythagore::BBox;
+///
+/// assert_eq!(
+///     BBox::<i32, 2>::default(),
+///     BBox::from([
+///        (Unbounded, Unbounded),
+///        (Unbounded, Unbounded),
+///     ])
+/// );
+/// ```
+impl<N: Copy + Scalar, const D: usize> Default for BBox<N, D> {
+    fn default() -> Self {
+        BBox {
+            ranges: [(Unbounded, Unbounded); D]
+        }
+    }
+}
+
+/// Grows bbox to hold every given point, as [`BBox::include`] does.
+/// Starting from [`BBox::empty`] gives the same result as [`BBox::bounding`], while a
+/// [`BBox::universe`] already holds every point and is left unchanged.
+///
+/// # Example
+/// ```
+/// use nalgebra::point;
+/// use pythagore::BBox;
+///
+/// let mut bbox = BBox::empty();
+/// bbox.extend([point![1, 4], point![3, 2]]);
+///
+/// assert_eq!(bbox, BBox::from(point![1, 2]..=point![3, 4]));
+/// ```
+impl<N: Copy + PartialOrd + Scalar, const D: usize> Extend<Point<N, D>> for BBox<N, D> {
+    fn extend<I: IntoIterator<Item = Point<N, D>>>(&mut self, iter: I) {
+        for point in iter {
+            *self = self.include(point);
+        }
+    }
+}
+
+/// Grows bbox to hold every given point, as [`BBox::include`] does
+impl<'a, N: Copy + PartialOrd + Scalar, const D: usize> Extend<&'a Point<N, D>> for BBox<N, D> {
+    fn extend<I: IntoIterator<Item = &'a Point<N, D>>>(&mut self, iter: I) {
+        for point in iter {
+            *self = self.include(point);
+        }
+    }
+}
+
+/// Grows bbox to hold every given bbox, as [`BBox::union_assign`] does. Empty boxes are ignored.
+///
+/// # Example
+/// ```
+/// use nalgebra::point;
+/// use pythagore::BBox;
+///
+/// let mut bbox = BBox::empty();
+/// bbox.extend([BBox::from(point![0, 0]..point![2, 2]), BBox::from(point![1, 3]..point![4, 5])]);
+///
+/// assert_eq!(bbox, BBox::from(point![0, 0]..point![4, 5]));
+/// ```
+impl<N: Copy + PartialOrd + Scalar, const D: usize> Extend<BBox<N, D>> for BBox<N, D> {
+    fn extend<I: IntoIterator<Item = BBox<N, D>>>(&mut self, iter: I) {
+        for bbox in iter {
+            self.union_assign(bbox);
+        }
+    }
+}
+
+/// Checks if bbox holds given point
+///
+/// # Example
+/// ```
+/// use nalgebra::point;
+/// use pythagore::{BBox, Holds};
+///
+/// assert!(BBox::from(point![0, 0]..point![5, 5]).holds(&point![2, 2]));
+/// ```
+impl<N: Scalar + PartialOrd, const D: usize> Holds<Point<N, D>> for BBox<N, D> {
+    fn holds(&self, object: &Point<N, D>) -> bool {
+        self.ranges.iter().enumerate()
+            .all(|(idx, range)| range.holds(unsafe { object.get_unchecked(idx) }))
+    }
+}
+
+/// Checks if bbox holds all points of given walker.
+/// As both are axis aligned, only the first and last points of the walker need to be checked.
+///
+/// # Example
+/// ```
+/// use nalgebra::point;
+/// use pythagore::{BBox, BBoxWalker, Holds};
+///
+/// assert!(BBox::from(point![0, 0]..point![5, 5]).holds(&BBoxWalker::new(point![1, 1], point![4, 4])));
+/// ```
+impl<N: Scalar + PartialOrd, const D: usize> Holds<BBoxWalker<N, D>> for BBox<N, D> {
+    fn holds(&self, object: &BBoxWalker<N, D>) -> bool {
+        object.is_empty() || (self.holds(object.first()) && self.holds(object.last()))
+    }
+}
+
+/// Checks if bbox holds every point of other bbox, see [`BBox::contains_bbox`]
+///
+/// # Example
+/// ```
+/// use nalgebra::point;
+/// use pythagore::{BBox, Holds};
+///
+/// assert!(BBox::from(point![0, 0]..point![5, 5]).holds(&BBox::from(point![1, 1]..point![4, 4])));
+/// ```
+impl<N: Copy + PartialOrd + Scalar, const D: usize> Holds<BBox<N, D>> for BBox<N, D> {
+    #[inline]
+    fn holds(&self, object: &BBox<N, D>) -> bool {
+        self.contains_bbox(object)
+    }
+}
+
+/// Returns true if bounding box cannot hold any point
+///
+/// # Example
+/// ```
+/// use nalgebra::point;
+/// use pythagore::{BBox, IsRangeEmpty};
+///
+/// assert!(BBox::from(point![5, 5]..point![0, 0]).is_range_empty());
+/// ```
+impl<N: Scalar + PartialOrd, const D: usize> IsRangeEmpty for BBox<N, D> {
+    fn is_range_empty(&self) -> bool {
+        self.ranges.iter().any(|range| range.is_range_empty())
+    }
+}
+
+impl<N: Copy + Scalar, const D: usize> DimBounds<N, D> for BBox<N, D> {
+    type Output = (Bound<N>, Bound<N>);
+
+    unsafe fn get_bounds_unchecked(&self, idx: usize) -> Self::Output {
+        *self.ranges.get_unchecked(idx)
+    }
+}
+
+impl<N: Copy + Scalar + Zero, const D: usize> PointBounds<N, D> for BBox<N, D> {
+    fn start_point(&self) -> Option<Point<N, D>> {
+        let mut point = Point::<N, D>::default();
+
+        for (idx, range) in self.ranges.iter().enumerate() {
+            if let Included(x) | Excluded(x) = range.0 {
+                unsafe { *point.get_unchecked_mut(idx) = x };
+            } else {
+                return None
+            }
+        }
+
+        Some(point)
+    }
+
+    fn end_point(&self) -> Option<Point<N, D>> {
+        let mut point = Point::<N, D>::default();
+
+        for (idx, range) in self.ranges.iter().enumerate() {
+            if let Included(x) | Excluded(x) = range.1 {
+                unsafe { *point.get_unchecked_mut(idx) = x };
+            } else {
+                return None
+            }
+        }
+
+        Some(point)
+    }
+}
+
+/// Excluded bounds are moved to the nearest included lattice value. If that would overflow (Excluded
+/// start at the scalar maximum, or Excluded end at its minimum) no point can be walked, so first or
+/// last point is None.
+impl<N: CheckedAdd + CheckedSub + Copy + One + Scalar + Zero, const D: usize> Walkable<N, D> for BBox<N, D> {
+    fn first_point(&self) -> Option<Point<N, D>> {
+        let mut point = Point::<N, D>::default();
+
+        for (idx, range) in self.ranges.iter().enumerate() {
+            match lattice_start(range.0)? {
+                Included(x) => unsafe { *point.get_unchecked_mut(idx) = x },
+                _ => return None,
+            }
+        }
+
+        Some(point)
+    }
+
+    fn last_point(&self) -> Option<Point<N, D>> {
+        let mut point = Point::<N, D>::default();
+
+        for (idx, range) in self.ranges.iter().enumerate() {
+            match lattice_end(range.1)? {
+                Included(x) => unsafe { *point.get_unchecked_mut(idx) = x },
+                _ => return None,
+            }
+        }
+
+        Some(point)
+    }
+}
+
+impl<N: Copy + PartialOrd + Scalar, const D: usize> Intersection for BBox<N, D> {
+    type Output = BBox<N, D>;
+
+    #[inline]
+    fn intersection(&self, rhs: &Self) -> Self::Output {
+        let mut result = *self;
+        result.intersect_assign(rhs);
+        result
+    }
+}
+
+impl<N: Copy + PartialOrd + Scalar, const D: usize> Intersection<Range<Point<N, D>>> for BBox<N, D> {
+    type Output = BBox<N, D>;
+
+    fn intersection(&self, rhs: &Range<Point<N, D>>) -> Self::Output {
+        let mut ranges = [(Unbounded, Unbounded); D];
+
+        for (idx, range) in ranges.iter_mut().enumerate() {
+            let lhs = unsafe { self.get_unchecked(idx) };
+
+            range.0 = max_bound(lhs.0, Included(unsafe { *rhs.start.get_unchecked(idx) }));
+            range.1 = min_bound(lhs.1, Excluded(unsafe { *rhs.end.get_unchecked(idx) }));
+        }
+
+        BBox::from(ranges)
+    }
+}
+
+impl<N: Copy + PartialOrd + Scalar, const D: usize> Intersection<RangeFrom<Point<N, D>>> for BBox<N, D> {
+    type Output = BBox<N, D>;
+
+    fn intersection(&self, rhs: &RangeFrom<Point<N, D>>) -> Self::Output {
+        let mut ranges = [(Unbounded, Unbounded); D];
+
+        for (idx, range) in ranges.iter_mut().enumerate() {
+            let lhs = unsafe { self.get_unchecked(idx) };
+
+            range.0 = max_bound(lhs.0, Included(unsafe { *rhs.start.get_unchecked(idx) }));
+            range.1 = lhs.1;
+        }
+
+        BBox::from(ranges)
+    }
+}
+
+impl<N: Copy + Scalar, const D: usize> Intersection<RangeFull> for BBox<N, D> {
+    type Output = BBox<N, D>;
+
+    #[inline]
+    fn intersection(&self, _: &RangeFull) -> Self::Output {
+        *self
+    }
+}
+
+impl<N: Copy + PartialOrd + Scalar, const D: usize> Intersection<RangeInclusive<Point<N, D>>> for BBox<N, D> {
+    type Output = BBox<N, D>;
+
+    fn intersection(&self, rhs: &RangeInclusive<Point<N, D>>) -> Self::Output {
+        let mut ranges = [(Unbounded, Unbounded); D];
+
+        for (idx, range) in ranges.iter_mut().enumerate() {
+            let lhs = unsafe { self.get_unchecked(idx) };
+
+            range.0 = max_bound(lhs.0, Included(unsafe { *rhs.start().get_unchecked(idx) }));
+            range.1 = min_bound(lhs.1, Included(unsafe { *rhs.end().get_unchecked(idx) }));
+        }
+
+        BBox::from(ranges)
+    }
+}
+
+impl<N: Copy + PartialOrd + Scalar, const D: usize> Intersection<RangeTo<Point<N, D>>> for BBox<N, D> {
+    type Output = BBox<N, D>;
+
+    fn intersection(&self, rhs: &RangeTo<Point<N, D>>) -> Self::Output {
+        let mut ranges = [(Unbounded, Unbounded); D];
+
+        for (idx, range) in ranges.iter_mut().enumerate() {
+            let lhs = unsafe { self.get_unchecked(idx) };
+
+            range.0 = lhs.0;
+            range.1 = min_bound(lhs.1, Excluded(unsafe { *rhs.end.get_unchecked(idx) }));
+        }
+
+        BBox::from(ranges)
+    }
+}
+
+impl<N: Copy + PartialOrd + Scalar, const D: usize> Intersection<RangeToInclusive<Point<N, D>>> for BBox<N, D> {
+    type Output = BBox<N, D>;
+
+    fn intersection(&self, rhs: &RangeToInclusive<Point<N, D>>) -> Self::Output {
+        let mut ranges = [(Unbounded, Unbounded); D];
+
+        for (idx, range) in ranges.iter_mut().enumerate() {
+            let lhs = unsafe { self.get_unchecked(idx) };
+
+            range.0 = lhs.0;
+            range.1 = min_bound(lhs.1, Included(unsafe { *rhs.end.get_unchecked(idx) }));
+        }
+
+        BBox::from(ranges)
+    }
+}
+
+impl<N: Copy + PartialOrd + Scalar, const D: usize> Intersection<(Bound<Point<N, D>>, Bound<Point<N, D>>)> for BBox<N, D> {
+    type Output = BBox<N, D>;
+
+    fn intersection(&self, rhs: &(Bound<Point<N, D>>, Bound<Point<N, D>>)) -> Self::Output {
+        let mut ranges = [(Unbounded, Unbounded); D];
+
+        for (idx, range) in ranges.iter_mut().enumerate() {
+            let lhs = unsafe { self.get_unchecked(idx) };
+            let rhs = unsafe { rhs.get_bounds_unchecked(idx) };
+
+            range.0 = max_bound(lhs.0, rhs.0);
+            range.1 = min_bound(lhs.1, rhs.1);
+        }
+
+        BBox::from(ranges)
+    }
+}
+
+impl<N, Rhs, const D: usize> Overlaps<Rhs> for BBox<N, D>
+where
+    N: Copy + PartialOrd + Scalar,
+    Rhs: DimBounds<N, D>,
+    <Rhs as DimBounds<N, D>>::Output: Overlaps<BBoxElement<N>>,
+{
+    fn overlaps(&self, rhs: &Rhs) -> bool {
+        self.ranges.iter().enumerate()
+            .all(|(idx, range)| unsafe { rhs.get_bounds_unchecked(idx) }.overlaps(range))
+    }
+}
+
+// Conversion
+impl<N: Scalar, const D: usize> AsRef<[BBoxElement<N>; D]> for BBox<N, D> {
+    #[inline]
+    fn as_ref(&self) -> &[BBoxElement<N>; D] {
+        &self.ranges
+    }
+}
+
+impl<N: Scalar, const D: usize> AsMut<[BBoxElement<N>; D]> for BBox<N, D> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [BBoxElement<N>; D] {
+        &mut self.ranges
+    }
+}
+
+/// Builds a bounding box from a set of ranges
+impl<N: Scalar, const D: usize> From<[BBoxElement<N>; D]> for BBox<N, D> {
+    fn from(ranges: [BBoxElement<N>; D]) -> Self {
+        BBox {
+            ranges
+        }
+    }
+}
+
+// Operators
+impl<N: Scalar, const D: usize> Index<usize> for BBox<N, D> {
+    type Output = BBoxElement<N>;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.ranges[index]
+    }
+}
+
+impl<N: Scalar, const D: usize> IndexMut<usize> for BBox<N, D> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.ranges[index]
+    }
+}
+
+impl<N: Scalar, const D: usize> PartialEq for BBox<N, D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ranges == other.ranges
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod from_points {
+        use na::{point, vector};
+        use super::*;
+
+        #[test]
+        fn test_float_points() {
+            assert_eq!(
+                BBox::from_points(&point![1.5, 4.0], &point![3.0, 2.5]),
+                BBox::from([
+                    (Included(1.5), Excluded(3.0)),
+                    (Included(2.5), Excluded(4.0)),
+                ])
+            );
+
+            assert_eq!(
+                BBox::from_points_included(&point![1.5, 4.0], &point![3.0, 2.5]),
+                BBox::from([
+                    (Included(1.5), Included(3.0)),
+                    (Included(2.5), Included(4.0)),
+                ])
+            );
+        }
+
+        #[test]
+        fn test_float_anchor_size() {
+            assert_eq!(
+                BBox::from_anchor_size(&point![1.0, 1.0], &vector![0.5, -0.5]),
+                BBox::from([
+                    (Included(1.0), Excluded(1.5)),
+                    (Included(0.5), Excluded(1.0)),
+                ])
+            );
+        }
+
+        #[test]
+        fn test_nan_coordinate() {
+            assert_eq!(
+                BBox::from_points(&point![f64::NAN, 4.0], &point![3.0, 2.5]),
+                BBox::from([
+                    (Included(3.0), Excluded(3.0)),
+                    (Included(2.5), Excluded(4.0)),
+                ])
+            );
+        }
+    }
+
+    mod center {
+        use na::{point, vector};
+        use super::*;
+
+        #[test]
+        fn test_half_extents_round_trip() {
+            let bbox = BBox::from_center_half_extents(&point![1, -2], &vector![3, 2]);
+
+            assert_eq!(bbox, BBox::from(point![-2, -4]..=point![4, 0]));
+            assert_eq!(bbox.center_and_half_extents(), Some((point![1, -2], vector![3, 2])));
+
+            let bbox = BBox::from_center_half_extents(&point![0.5, 1.0, -1.5], &vector![0.25, 1.0, 2.0]);
+
+            assert_eq!(bbox, BBox::from(point![0.25, 0.0, -3.5]..=point![0.75, 2.0, 0.5]));
+            assert_eq!(bbox.center_and_half_extents(), Some((point![0.5, 1.0, -1.5], vector![0.25, 1.0, 2.0])));
+        }
+
+        #[test]
+        fn test_size_round_trip() {
+            let bbox = BBox::from_center_size(&point![1.0, -2.0], &vector![3.0, 2.0]);
+
+            assert_eq!(bbox, BBox::from(point![-0.5, -3.0]..point![2.5, -1.0]));
+            assert_eq!(bbox.center_and_half_extents(), Some((point![1.0, -2.0], vector![1.5, 1.0])));
+
+            let bbox = BBox::from_center_size(&point![1, -2, 0], &vector![4, 2, 6]);
+
+            assert_eq!(bbox, BBox::from(point![-1, -3, -3]..point![3, -1, 3]));
+            assert_eq!(bbox.center_and_half_extents(), Some((point![1, -2, 0], vector![2, 1, 3])));
+        }
+
+        #[test]
+        fn test_odd_integer_size() {
+            let bbox = BBox::from_center_size(&point![-3, 2], &vector![5, 3]);
+
+            assert_eq!(bbox, BBox::from(point![-5, 1]..point![0, 4]));
+            assert_eq!(bbox.center_and_half_extents(), Some((point![-3, 2], vector![2, 1])));
+        }
+
+        #[test]
+        fn test_negative_half_extents() {
+            assert!(BBox::from_center_half_extents(&point![0, 0], &vector![-1, 1]).is_range_empty());
+            assert!(BBox::from_center_size(&point![0, 0], &vector![2, -2]).is_range_empty());
+        }
+
+        #[test]
+        fn test_unbounded_bbox() {
+            assert_eq!(BBox::from(point![0, 0]..).center_and_half_extents(), None);
+        }
+    }
+
+    mod canonicalize {
+        use na::point;
+        use super::*;
+
+        #[test]
+        fn test_same_lattice() {
+            let a = BBox::from(point![0, 0]..=point![4, 4]);
+            let b = BBox::from(point![0, 0]..point![5, 5]);
+            let c = BBox::from((Excluded(point![-1, -1]), Excluded(point![5, 5])));
+
+            assert_ne!(a, b);
+            assert_eq!(a.canonicalize(), b.canonicalize());
+            assert!(a.lattice_eq(&c));
+            assert!(!a.lattice_eq(&BBox::from(point![0, 0]..=point![4, 5])));
+        }
+
+        #[test]
+        fn test_same_points() {
+            let bbox = BBox::from([(Excluded(-2), Included(3)), (Included(1), Excluded(4)), (Excluded(0), Excluded(2))]);
+            let canonical = bbox.canonicalize();
+
+            assert_eq!(
+                bbox.walk().unwrap().iter().collect::<Vec<_>>(),
+                canonical.walk().unwrap().iter().collect::<Vec<_>>()
+            );
+        }
+
+        #[test]
+        fn test_numeric_limits() {
+            let full = BBox::from([(Included(0u8), Included(u8::MAX))]);
+
+            assert_eq!(full.canonicalize(), full);
+            assert!(!full.canonicalize().is_range_empty());
+            assert!(!full.lattice_eq(&BBox::empty()));
+            assert!(full.lattice_eq(&BBox::from([(Included(0), Included(u8::MAX))])));
+            assert!(!full.lattice_eq(&BBox::from([(Included(0), Excluded(u8::MAX))])));
+
+            let bbox = BBox::from([(Excluded(i8::MIN), Included(i8::MAX))]);
+
+            assert_eq!(bbox.canonicalize(), BBox::from([(Included(-127), Included(i8::MAX))]));
+            assert_eq!(bbox.canonicalize().canonicalize(), bbox.canonicalize());
+        }
+
+        #[test]
+        fn test_idempotent() {
+            let bbox = BBox::from([(Excluded(-2), Unbounded), (Unbounded, Included(4))]);
+
+            assert_eq!(bbox.canonicalize(), BBox::from([(Included(-1), Unbounded), (Unbounded, Excluded(5))]));
+            assert_eq!(bbox.canonicalize().canonicalize(), bbox.canonicalize());
+        }
+
+        #[test]
+        fn test_empty_boxes() {
+            let canonical = BBox::<i32, 2>::empty().canonicalize();
+
+            assert_eq!(canonical, BBox::empty());
+
+            assert_eq!(BBox::from(point![5, 5]..point![0, 0]).canonicalize(), canonical);
+            assert_eq!(BBox::from((Excluded(point![0, 0]), Excluded(point![1, 1]))).canonicalize(), canonical);
+            assert_eq!(BBox::from([(Excluded(u8::MAX), Included(u8::MAX))]).canonicalize(), BBox::<u8, 1>::empty());
+            assert!(canonical.is_range_empty());
+        }
+    }
+
+    mod corners {
+        use na::point;
+        use super::*;
+
+        #[test]
+        fn test_2d_corners() {
+            let bbox = BBox::from([(Included(-1), Excluded(2)), (Excluded(3), Included(4))]);
+
+            assert_eq!(
+                bbox.corners_array(),
+                Some([point![-1, 3], point![-1, 4], point![2, 3], point![2, 4]])
+            );
+        }
+
+        #[test]
+        fn test_3d_corners() {
+            let bbox = BBox::from(point![0, 0, 0]..point![1, 2, 3]);
+            let corners = bbox.corners_array().unwrap();
+
+            assert_eq!(corners[0], point![0, 0, 0]);
+            assert_eq!(corners[1], point![0, 0, 3]);
+            assert_eq!(corners[2], point![0, 2, 0]);
+            assert_eq!(corners[4], point![1, 0, 0]);
+            assert_eq!(corners[7], point![1, 2, 3]);
+
+            for (idx, corner) in corners.iter().enumerate() {
+                assert!(!corners[..idx].contains(corner));
+            }
+        }
+
+        #[test]
+        fn test_stable_order() {
+            let bbox = BBox::from(point![0, 0, 0, 0]..point![1, 2, 3, 4]);
+
+            assert_eq!(bbox.corners().unwrap().len(), 16);
+            assert!(bbox.corners().unwrap().eq(bbox.corners().unwrap()));
+        }
+
+        #[test]
+        fn test_unbounded_axis() {
+            assert!(BBox::from(point![0, 0]..).corners().is_none());
+            assert!(BBox::from([(Unbounded, Included(0)), (Included(0), Included(1))]).corners_array().is_none());
+        }
+    }
+
+    mod corner_queries {
+        use na::{point, vector};
+        use super::*;
+
+        #[test]
+        fn test_nearest_corner_2d() {
+            let bbox = BBox::from(point![0, 0]..point![4, 4]);
+
+            assert_eq!(bbox.nearest_corner_to(&point![-1, -1]), Some(point![0, 0]));
+            assert_eq!(bbox.nearest_corner_to(&point![5, -1]), Some(point![4, 0]));
+            assert_eq!(bbox.nearest_corner_to(&point![-1, 5]), Some(point![0, 4]));
+            assert_eq!(bbox.nearest_corner_to(&point![3, 3]), Some(point![4, 4]));
+            assert_eq!(bbox.nearest_corner_to(&point![2, 2]), Some(point![0, 0]));
+            assert_eq!(BBox::from(point![0, 0]..).nearest_corner_to(&point![2, 2]), None);
+        }
+
+        #[test]
+        fn test_farthest_corner_3d() {
+            let bbox = BBox::from(point![0, 0, 0]..point![4, 4, 4]);
+
+            for (x, y, z) in [(-1, -1, -1), (5, -1, 3), (1, 6, -2), (3, 3, 3), (-8, 2, 9)] {
+                let expected = point![
+                    if x > 2 { 0 } else { 4 },
+                    if y > 2 { 0 } else { 4 },
+                    if z > 2 { 0 } else { 4 }
+                ];
+
+                assert_eq!(bbox.farthest_corner_from(&point![x, y, z]), Some(expected));
+            }
+        }
+
+        #[test]
+        fn test_support_point() {
+            let bbox = BBox::from(point![-1.0, 0.0, 2.0]..point![1.0, 3.0, 5.0]);
+
+            assert_eq!(bbox.support_point(&vector![1.0, 0.0, 0.0]), Some(point![1.0, 3.0, 5.0]));
+            assert_eq!(bbox.support_point(&vector![0.0, -1.0, 0.0]), Some(point![1.0, 0.0, 5.0]));
+            assert_eq!(bbox.support_point(&vector![-1.0, -1.0, -1.0]), Some(point![-1.0, 0.0, 2.0]));
+            assert_eq!(bbox.support_point(&vector![1.0, -1.0, 1.0]), Some(point![1.0, 0.0, 5.0]));
+            assert_eq!(BBox::from(..point![1.0, 3.0, 5.0]).support_point(&vector![1.0, 1.0, 1.0]), None);
+        }
+
+        #[test]
+        fn test_support_matches_farthest_corner() {
+            let bbox = BBox::from(point![-2, 1]..point![4, 5]);
+            let center = point![1, 3];
+
+            for dir in [vector![1, 0], vector![0, -1], vector![1, 1], vector![-1, 1], vector![-1, -1], vector![0, 0]] {
+                assert_eq!(bbox.support_point(&dir), bbox.farthest_corner_from(&(center - dir * 100)));
+            }
+        }
+
+        #[test]
+        fn test_scalar_limits() {
+            let bbox = BBox::from(point![0, 0]..point![i32::MAX, 5]);
+
+            assert_eq!(bbox.nearest_corner_to(&point![i32::MAX, 0]), Some(point![i32::MAX, 0]));
+            assert_eq!(bbox.farthest_corner_from(&point![i32::MAX, 0]), Some(point![0, 5]));
+
+            let bbox = BBox::from(point![i32::MIN]..point![i32::MAX]);
+
+            assert_eq!(bbox.nearest_corner_to(&point![-1]), Some(point![i32::MIN]));
+            assert_eq!(bbox.nearest_corner_to(&point![0]), Some(point![i32::MAX]));
+            assert_eq!(bbox.farthest_corner_from(&point![i32::MIN]), Some(point![i32::MAX]));
+            assert_eq!(bbox.farthest_corner_from(&point![i32::MAX]), Some(point![i32::MIN]));
+
+            let bbox = BBox::from(point![u8::MAX]..point![0]);
+
+            assert_eq!(bbox.nearest_corner_to(&point![200]), Some(point![u8::MAX]));
+            assert_eq!(bbox.farthest_corner_from(&point![200]), Some(point![0]));
+        }
+    }
+
+    mod difference {
+        use na::point;
+        use super::*;
+
+        #[test]
+        fn test_disjoint_boxes() {
+            let bbox = BBox::from(point![0, 0]..point![5, 5]);
+
+            assert_eq!(bbox.difference(&BBox::from(point![5, 0]..point![8, 5])), vec![bbox]);
+        }
+
+        #[test]
+        fn test_covering_box() {
+            let bbox = BBox::from(point![0, 0]..point![5, 5]);
+
+            assert_eq!(bbox.difference(&BBox::from(point![-1, -1]..=point![5, 5])), vec![]);
+            assert_eq!(bbox.difference(&BBox::default()), vec![]);
+        }
+
+        #[test]
+        fn test_hole() {
+            let parts = BBox::from(point![0, 0]..point![6, 6]).difference(&BBox::from(point![2, 2]..point![4, 4]));
+
+            assert_eq!(
+                parts,
+                vec![
+                    BBox::from(point![0, 0]..point![2, 6]),
+                    BBox::from(point![4, 0]..point![6, 6]),
+                    BBox::from(point![2, 0]..point![4, 2]),
+                    BBox::from(point![2, 4]..point![4, 6]),
+                ]
+            );
+        }
+
+        #[test]
+        fn test_unbounded_boxes() {
+            let parts = BBox::from(..point![5, 5]).difference(&BBox::from(point![0, 0]..));
+
+            assert_eq!(
+                parts,
+                vec![
+                    BBox::from([(Unbounded, Excluded(0)), (Unbounded, Excluded(5))]),
+                    BBox::from([(Included(0), Excluded(5)), (Unbounded, Excluded(0))]),
+                ]
+            );
+        }
+
+        #[test]
+        fn test_lattice_partition() {
+            let values = [Unbounded, Included(0), Excluded(0), Included(2), Excluded(2), Included(3), Excluded(4)];
+            let ranges: Vec<_> = values.iter()
+                .flat_map(|start| values.iter().map(move |end| (*start, *end)))
+                .filter(|range| !range.is_range_empty())
+                .collect();
+
+            for ax in ranges.iter().step_by(3) {
+                for ay in ranges.iter().step_by(4) {
+                    let a = BBox::from([*ax, *ay]);
+
+                    for bx in ranges.iter().step_by(2) {
+                        for by in ranges.iter().step_by(5) {
+                            let b = BBox::from([*bx, *by]);
+                            let parts = a.difference(&b);
+                            let inter = a.intersection(&b);
+
+                            for pt in BBoxWalker::new(point![-2, -2], point![6, 6]).iter() {
+                                let count = parts.iter().filter(|part| part.holds(&pt)).count()
+                                    + usize::from(inter.holds(&pt));
+
+                                assert_eq!(count, usize::from(a.holds(&pt)), "{a:?} - {b:?} at {pt}");
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    mod holds {
+        use na::point;
+        use super::*;
+
+        #[test]
+        fn test_all_point_coords_in_ranges() {
+            assert!(BBox::from(point![0, 0]..point![5, 5]).holds(&point![2, 2]));
+        }
+
+        #[test]
+        fn test_some_point_coords_lower_than_start() {
+            assert!(!BBox::from(point![0, 0]..point![5, 5]).holds(&point![-2, 2]));
+            assert!(!BBox::from(point![0, 0]..point![5, 5]).holds(&point![2, -2]));
+        }
+
+        #[test]
+        fn test_some_point_coords_greater_than_end() {
+            assert!(!BBox::from(point![0, 0]..point![5, 5]).holds(&point![7, 2]));
+            assert!(!BBox::from(point![0, 0]..point![5, 5]).holds(&point![2, 7]));
+        }
+    }
+
+    mod close {
+        use na::point;
+        use super::*;
+
+        #[test]
+        fn test_bound_kinds() {
+            let bbox = BBox::from([(Included(0), Excluded(5)), (Excluded(-2), Included(3))]);
+
+            assert!(bbox.is_bounded());
+            assert!(!bbox.is_closed());
+            assert_eq!(bbox.bounded_axes(), [true, true]);
+
+            let bbox = BBox::from([(Included(0), Included(5)), (Included(-2), Included(3))]);
+
+            assert!(bbox.is_bounded());
+            assert!(bbox.is_closed());
+        }
+
+        #[test]
+        fn test_unbounded_axis() {
+            let bbox = BBox::from([(Included(0), Excluded(5)), (Excluded(-2), Unbounded), (Unbounded, Unbounded)]);
+
+            assert!(!bbox.is_bounded());
+            assert!(!bbox.is_closed());
+            assert_eq!(bbox.bounded_axes(), [true, false, false]);
+            assert_eq!(bbox.close(), None);
+        }
+
+        #[test]
+        fn test_close_mixed_bounds() {
+            let bbox = BBox::from([(Included(0), Excluded(5)), (Excluded(-2), Included(3))]);
+            let closed = bbox.close().unwrap();
+
+            assert_eq!(closed, BBox::from(point![0, -1]..=point![4, 3]));
+            assert!(closed.is_closed());
+            assert_eq!(closed.start_point(), bbox.first_point());
+            assert_eq!(closed.end_point(), bbox.last_point());
+        }
+
+        #[test]
+        fn test_close_at_numeric_limits() {
+            let bbox = BBox::from([(Excluded(u8::MAX), Included(u8::MAX))]);
+
+            assert_eq!(bbox.close(), None);
+            assert_eq!(bbox.first_point(), None);
+
+            let bbox = BBox::from([(Included(i8::MIN), Excluded(i8::MIN))]);
+
+            assert_eq!(bbox.close(), None);
+            assert_eq!(bbox.last_point(), None);
+
+            let bbox = BBox::from([(Excluded(i8::MIN), Excluded(i8::MAX))]);
+            let closed = bbox.close().unwrap();
+
+            assert_eq!(closed, BBox::from([(Included(-127), Included(126))]));
+            assert_eq!(closed.start_point(), bbox.first_point());
+            assert_eq!(closed.end_point(), bbox.last_point());
+        }
+    }
+
+    mod intersect_ray {
+        use na::{point, vector};
+        use super::*;
+
+        #[test]
+        fn test_ray_through_middle() {
+            let bbox = BBox::from(point![0.0, 0.0, 0.0]..=point![1.0, 1.0, 1.0]);
+
+            assert_eq!(bbox.intersect_ray(&point![0.5, 0.5, -1.0], &vector![0.0, 0.0, 1.0]), Some((1.0, 2.0)));
+            assert_eq!(bbox.intersect_ray(&point![0.5, 0.5, 2.0], &vector![0.0, 0.0, -1.0]), Some((1.0, 2.0)));
+            assert_eq!(bbox.intersect_ray(&point![-1.0, -1.0, -1.0], &vector![1.0, 1.0, 1.0]), Some((1.0, 2.0)));
+        }
+
+        #[test]
+        fn test_ray_missing() {
+            let bbox = BBox::from(point![0.0, 0.0]..point![1.0, 1.0]);
+
+            assert_eq!(bbox.intersect_ray(&point![-1.0, 0.5], &vector![-1.0, 0.0]), None);
+            assert_eq!(bbox.intersect_ray(&point![-1.0, 0.0], &vector![1.0, 2.0]), None);
+            assert_eq!(bbox.intersect_ray(&point![0.5, 1.5], &vector![1.0, 0.0]), None);
+        }
+
+        #[test]
+        fn test_ray_starting_inside() {
+            let bbox = BBox::from(point![0.0, 0.0]..point![1.0, 1.0]);
+
+            assert_eq!(bbox.intersect_ray(&point![0.25, 0.5], &vector![1.0, 0.0]), Some((-0.25, 0.75)));
+            assert_eq!(bbox.intersect_segment(&point![0.25, 0.5], &point![1.25, 0.5]), Some((0.0, 0.75)));
+        }
+
+        #[test]
+        fn test_ray_grazing_excluded_face() {
+            let bbox = BBox::from(point![0.0, 0.0]..point![1.0, 1.0]);
+
+            assert_eq!(bbox.intersect_ray(&point![-1.0, 1.0], &vector![1.0, 0.0]), Some((1.0, 2.0)));
+        }
+
+        #[test]
+        fn test_unbounded_side() {
+            let bbox = BBox::from([(Included(0.0), Unbounded), (Unbounded, Unbounded)]);
+
+            assert_eq!(bbox.intersect_ray(&point![-1.0, 5.0], &vector![1.0, 0.0]), Some((1.0, f64::INFINITY)));
+            assert_eq!(bbox.intersect_ray(&point![-1.0, 5.0], &vector![-1.0, 0.0]), None);
+        }
+
+        #[test]
+        fn test_empty_bbox() {
+            let bbox = BBox::from(point![1.0, 0.0]..point![0.0, 1.0]);
+
+            assert_eq!(bbox.intersect_ray(&point![-1.0, 0.5], &vector![1.0, 0.0]), None);
+        }
+
+        #[test]
+        fn test_segment() {
+            let bbox = BBox::from(point![0.0, 0.0]..point![1.0, 1.0]);
+
+            assert_eq!(bbox.intersect_segment(&point![-1.0, 0.5], &point![3.0, 0.5]), Some((0.25, 0.5)));
+            assert_eq!(bbox.intersect_segment(&point![-1.0, 0.5], &point![-0.5, 0.5]), None);
+            assert_eq!(bbox.intersect_segment(&point![0.25, 0.25], &point![0.75, 0.75]), Some((0.0, 1.0)));
+        }
+    }
+
+    mod clip_segment {
+        use na::point;
+        use super::*;
+
+        #[test]
+        fn test_inside_segment() {
+            let bbox = BBox::from(point![0.0, 0.0]..point![1.0, 1.0]);
+            let (a, b) = (point![0.1, 0.7], point![0.9, 0.3]);
+
+            assert_eq!(bbox.clip_segment(&a, &b), Some((a, b)));
+        }
+
+        #[test]
+        fn test_crossing_segment() {
+            let bbox = BBox::from(point![0.0, 0.0]..point![1.0, 1.0]);
+            let (start, end) = bbox.clip_segment(&point![-0.5, 0.0], &point![1.5, 1.0]).unwrap();
+
+            assert!((start - point![0.0, 0.25]).norm() < 1e-12);
+            assert!((end - point![1.0, 0.75]).norm() < 1e-12);
+        }
+
+        #[test]
+        fn test_corner() {
+            let bbox = BBox::from(point![0.0, 0.0]..=point![1.0, 1.0]);
+
+            assert_eq!(
+                bbox.clip_segment(&point![0.0, 2.0], &point![2.0, 0.0]),
+                Some((point![1.0, 1.0], point![1.0, 1.0]))
+            );
+            assert_eq!(
+                BBox::from(point![0.0, 0.0]..point![1.0, 1.0]).clip_segment(&point![0.0, 2.0], &point![2.0, 0.0]),
+                None
+            );
+        }
+
+        #[test]
+        fn test_outside_segment() {
+            let bbox = BBox::from(point![0.0, 0.0]..point![1.0, 1.0]);
+
+            assert_eq!(bbox.clip_segment(&point![2.0, 0.0], &point![3.0, 1.0]), None);
+            assert_eq!(bbox.clip_segment(&point![-1.0, 0.5], &point![-0.5, 0.5]), None);
+        }
+
+        #[test]
+        fn test_along_excluded_face() {
+            let bbox = BBox::from([(Excluded(0.0), Included(1.0)), (Included(0.0), Included(1.0))]);
+
+            assert_eq!(bbox.clip_segment(&point![0.0, -1.0], &point![0.0, 2.0]), None);
+            assert_eq!(
+                bbox.clip_segment(&point![1.0, -1.0], &point![1.0, 2.0]),
+                Some((point![1.0, 0.0], point![1.0, 1.0]))
+            );
+        }
+
+        #[test]
+        fn test_unbounded_axis() {
+            let bbox = BBox::from([(Unbounded, Unbounded), (Included(0.0), Included(1.0))]);
+
+            assert_eq!(
+                bbox.clip_segment(&point![-5.0, -1.0], &point![5.0, 1.0]),
+                Some((point![0.0, 0.0], point![5.0, 1.0]))
+            );
+        }
+    }
+
+    mod manhattan_distance {
+        use na::point;
+        use super::*;
+
+        #[test]
+        fn test_held_point() {
+            let bbox = BBox::from(point![-4, -4]..point![-1, -1]);
+
+            assert_eq!(bbox.manhattan_distance_to_point(&point![-4, -2]), Some(0));
+            assert_eq!(bbox.manhattan_distance_to_point(&point![-2, -2]), Some(0));
+        }
+
+        #[test]
+        fn test_diagonal_point() {
+            let bbox = BBox::from(point![-4, -4]..point![-1, -1]);
+
+            assert_eq!(bbox.manhattan_distance_to_point(&point![0, 0]), Some(4));
+            assert_eq!(bbox.manhattan_distance_to_point(&point![-6, 1]), Some(5));
+            assert_eq!(bbox.manhattan_distance_to_point(&point![-7, -7]), Some(6));
+        }
+
+        #[test]
+        fn test_excluded_bounds() {
+            let bbox = BBox::from([(Excluded(0), Excluded(5)), (Included(0), Included(5))]);
+
+            assert_eq!(bbox.manhattan_distance_to_point(&point![0, 6]), Some(2));
+            assert_eq!(bbox.manhattan_distance_to_point(&point![5, 2]), Some(1));
+        }
+
+        #[test]
+        fn test_unbounded_axis() {
+            let bbox = BBox::from([(Unbounded, Included(0)), (Included(0), Unbounded)]);
+
+            assert_eq!(bbox.manhattan_distance_to_point(&point![-100, 100]), Some(0));
+            assert_eq!(bbox.manhattan_distance_to_point(&point![3, -2]), Some(5));
+        }
+
+        #[test]
+        fn test_numeric_limits() {
+            let bbox = BBox::from([(Excluded(i8::MAX), Unbounded)]);
+
+            assert_eq!(bbox.manhattan_distance_to_point(&point![0]), None);
+
+            let bbox = BBox::from([(Included(0u8), Excluded(0))]);
+
+            assert_eq!(bbox.manhattan_distance_to_point(&point![5]), None);
+
+            let bbox = BBox::from([(Included(i8::MAX), Unbounded), (Excluded(-2), Excluded(0))]);
+
+            assert_eq!(bbox.manhattan_distance_to_point(&point![0, -1]), Some(127));
+            assert_eq!(bbox.manhattan_distance_to_point(&point![-1, -1]), None);
+            assert_eq!(bbox.manhattan_distance_to_point(&point![0, -3]), None);
+            assert_eq!(BBox::from([(Included(i8::MAX), Unbounded)]).manhattan_distance_to_point(&point![i8::MIN]), None);
+        }
+    }
+
+    mod axis_accessors {
+        use na::point;
+        use super::*;
+
+        #[test]
+        fn test_2d_accessors() {
+            let mut bbox = BBox::from([(Included(0), Excluded(5)), (Unbounded, Included(3))]);
+
+            assert_eq!(bbox.x_range(), (Included(0), Excluded(5)));
+            assert_eq!(bbox.y_range(), (Unbounded, Included(3)));
+            assert_eq!(bbox.width(), Some(5));
+            assert_eq!(bbox.height(), None);
+
+            bbox.set_y_range((Excluded(-1), Included(3)));
+            bbox.set_x_range((Included(1), Excluded(5)));
+
+            assert_eq!(bbox, BBox::from([(Included(1), Excluded(5)), (Excluded(-1), Included(3))]));
+            assert_eq!(bbox.width(), Some(4));
+            assert_eq!(bbox.height(), Some(4));
+        }
+
+        #[test]
+        fn test_3d_accessors() {
+            let mut bbox = BBox::from(point![0.0, 1.0, 2.0]..point![1.0, 3.0, 6.0]);
+
+            assert_eq!(bbox.z_range(), (Included(2.0), Excluded(6.0)));
+            assert_eq!((bbox.width(), bbox.height(), bbox.depth()), (Some(1.0), Some(2.0), Some(4.0)));
+
+            bbox.set_z_range((Unbounded, Unbounded));
+
+            assert_eq!(bbox.depth(), None);
+        }
+    }
+
+    mod project {
+        use na::point;
+        use super::*;
+
+        #[test]
+        fn test_project_xz() {
+            let bbox = BBox::from([(Included(0), Excluded(5)), (Unbounded, Included(3)), (Excluded(-2), Unbounded)]);
+
+            assert_eq!(
+                bbox.project([0, 2]),
+                BBox::from([(Included(0), Excluded(5)), (Excluded(-2), Unbounded)])
+            );
+            assert_eq!(
+                bbox.project([2, 1, 0]),
+                BBox::from([(Excluded(-2), Unbounded), (Unbounded, Included(3)), (Included(0), Excluded(5))])
+            );
+        }
+
+        #[test]
+        fn test_extrude_projection() {
+            let bbox = BBox::from(point![0, 0, 0]..point![3, 3, 3]);
+            let extruded = bbox.project([0, 1]).extrude((Unbounded, Unbounded));
+
+            for pt in BBoxWalker::new(point![-1, -1, -1], point![4, 4, 4]).iter() {
+                assert_eq!(extruded.holds(&pt), bbox.project([0, 1]).holds(&point![pt.x, pt.y]));
+                assert_eq!(extruded.holds(&pt), bbox.holds(&point![pt.x, pt.y, 0]));
+            }
+        }
+
+        #[test]
+        #[should_panic(expected = "Axis 3 is out of bounds for a 3 dimensions bbox")]
+        fn test_project_out_of_bounds() {
+            BBox::from(point![0, 0, 0]..point![3, 3, 3]).project([0, 3]);
+        }
+
+        #[test]
+        #[should_panic(expected = "Axis 1 is selected twice")]
+        fn test_project_duplicate_axis() {
+            BBox::from(point![0, 0, 0]..point![3, 3, 3]).project([1, 1]);
+        }
+    }
+
+    mod range_bounds {
+        use alloc::collections::BTreeMap;
+        use na::point;
+        use super::*;
+
+        #[test]
+        fn test_btree_map_range() {
+            let map: BTreeMap<i32, ()> = (0..10).map(|k| (k, ())).collect();
+            let keys = |bbox: &BBox<i32, 2>, axis| map.range(bbox.range_bounds(axis)).map(|(k, _)| *k).collect::<Vec<_>>();
+
+            let bbox = BBox::from([(Included(2), Included(4)), (Excluded(2), Excluded(5))]);
+            assert_eq!(keys(&bbox, 0), vec![2, 3, 4]);
+            assert_eq!(keys(&bbox, 1), vec![3, 4]);
+
+            let bbox = BBox::from(..point![3, 3]);
+            assert_eq!(keys(&bbox, 0), vec![0, 1, 2]);
+            assert_eq!(keys(&BBox::from(point![7, 7]..), 1), vec![7, 8, 9]);
+        }
+
+        #[test]
+        fn test_contains_on_axis() {
+            let bbox = BBox::from([(Excluded(0), Unbounded), (Unbounded, Included(5))]);
+
+            assert!(!bbox.contains_on_axis(0, &0));
+            assert!(bbox.contains_on_axis(0, &100));
+            assert!(bbox.contains_on_axis(1, &5));
+            assert!(!bbox.contains_on_axis(1, &6));
+        }
+    }
+
+    mod split_at {
+        use na::point;
+        use super::*;
+
+        #[test]
+        fn test_split_inside() {
+            let bbox = BBox::from([(Included(0), Included(4)), (Excluded(0), Excluded(4))]);
+
+            assert_eq!(
+                bbox.split_at(1, 2),
+                (
+                    BBox::from([(Included(0), Included(4)), (Excluded(0), Excluded(2))]),
+                    BBox::from([(Included(0), Included(4)), (Included(2), Excluded(4))]),
+                )
+            );
+        }
+
+        #[test]
+        fn test_split_outside() {
+            let bbox = BBox::from(point![0, 0]..point![5, 5]);
+            let (left, right) = bbox.split_at(0, 8);
+
+            assert_eq!(left, bbox);
+            assert!(right.is_range_empty());
+        }
+
+        #[test]
+        fn test_split_lattice_points() {
+            let bbox = BBox::from([(Included(0), Included(4)), (Excluded(-1), Excluded(3))]);
+
+            for at in -1..6 {
+                let (left, right) = bbox.split_at(0, at);
+
+                for pt in BBoxWalker::new(point![-2, -2], point![6, 4]).iter() {
+                    assert!(!(left.holds(&pt) && right.holds(&pt)), "{pt} split at {at}");
+                    assert_eq!(left.holds(&pt) || right.holds(&pt), bbox.holds(&pt), "{pt} split at {at}");
+                }
+            }
+        }
+    }
+
+    mod holds_points {
+        use na::point;
+        use super::*;
+
+        #[test]
+        fn test_holds_all() {
+            let bbox = BBox::from(point![0, 0]..point![5, 5]);
+
+            assert!(bbox.holds_all([point![0, 0], point![4, 4], point![2, 3]]));
+            assert!(!bbox.holds_all([point![0, 0], point![5, 4], point![2, 3]]));
+            assert!(bbox.holds_all([]));
+        }
+
+        #[test]
+        fn test_holds_any() {
+            let bbox = BBox::from(point![0, 0]..point![5, 5]);
+
+            assert!(bbox.holds_any([point![-1, 0], point![4, 4]]));
+            assert!(!bbox.holds_any([point![-1, 0], point![5, 4]]));
+            assert!(!bbox.holds_any([]));
+        }
+
+        #[test]
+        fn test_partition_points() {
+            let bbox = BBox::from(point![0, 0]..point![5, 5]);
+
+            assert_eq!(
+                bbox.partition_points([point![-1, 0], point![4, 4], point![5, 4], point![0, 0]]),
+                (vec![point![4, 4], point![0, 0]], vec![point![-1, 0], point![5, 4]])
+            );
+        }
+
+        #[test]
+        fn test_holds_walker() {
+            let bbox = BBox::from(point![0, 0]..point![5, 5]);
+
+            assert!(bbox.holds(&BBoxWalker::new(point![0, 0], point![4, 4])));
+            assert!(bbox.holds(&BBoxWalker::new(point![2, 1], point![3, 3])));
+            assert!(!bbox.holds(&BBoxWalker::new(point![2, 1], point![5, 3])));
+            assert!(!bbox.holds(&BBoxWalker::new(point![-1, -1], point![6, 6])));
+        }
+    }
+
+    mod holds_many {
+        use na::Point2;
+        use rand::{Rng, SeedableRng};
+        use rand::rngs::StdRng;
+        use super::*;
+
+        fn random_bound(rng: &mut StdRng) -> Bound<f32> {
+            match rng.gen_range(0..3) {
+                0 => Included(rng.gen_range(-4..4) as f32),
+                1 => Excluded(rng.gen_range(-4..4) as f32),
+                _ => Unbounded,
+            }
+        }
+
+        fn random_points(rng: &mut StdRng, count: usize) -> Vec<Point2<f32>> {
+            (0..count)
+                .map(|_| Point2::new(rng.gen_range(-5..5) as f32, rng.gen_range(-5..5) as f32 * 0.5))
+                .collect()
+        }
+
+        #[test]
+        fn test_matches_holds() {
+            let mut rng = StdRng::seed_from_u64(42);
+            let points = random_points(&mut rng, 200);
+            let mut out = Vec::new();
+
+            for _ in 0..200 {
+                let bbox = BBox::from(core::array::from_fn(|_| (random_bound(&mut rng), random_bound(&mut rng))));
+                bbox.holds_many(&points, &mut out);
+
+                assert_eq!(out.len(), points.len());
+
+                for (pt, res) in points.iter().zip(out.iter()) {
+                    assert_eq!(*res, bbox.holds(pt), "{bbox:?} {pt}");
+                }
+            }
+        }
+
+        #[test]
+        fn test_nan_coordinates() {
+            let bbox = BBox::from(Point2::new(0.0, 0.0)..Point2::new(1.0, 1.0));
+
+            assert_eq!(bbox.holds_mask(&[Point2::new(f32::NAN, 0.0), Point2::new(0.5, 0.5)]), vec![false, true]);
+            assert_eq!(BBox::<f32, 2>::universe().holds_mask(&[Point2::new(f32::NAN, 0.0)]), vec![true]);
+        }
+
+        /// Run with `cargo test --release -- --ignored bench_holds_many --nocapture`
+        #[cfg(feature = "std")]
+        #[test]
+        #[ignore]
+        fn bench_holds_many() {
+            use std::hint::black_box;
+            use std::time::Instant;
+
+            let mut rng = StdRng::seed_from_u64(42);
+            let points = random_points(&mut rng, 1_000_000);
+            let bbox = BBox::from([(Included(-2.0), Excluded(3.0)), (Excluded(-1.0), Included(1.5))]);
+            let mut out = Vec::new();
+
+            let start = Instant::now();
+            black_box(&bbox).holds_many(&points, &mut out);
+            std::println!("holds_many: {:?} ({} hits)", start.elapsed(), out.iter().filter(|res| **res).count());
+
+            let start = Instant::now();
+            let naive: Vec<_> = points.iter().map(|pt| black_box(&bbox).holds(pt)).collect();
+            std::println!("holds: {:?} ({} hits)", start.elapsed(), naive.iter().filter(|res| **res).count());
+        }
+    }
+
+    mod bounding {
+        use na::point;
+        use rand::{Rng, SeedableRng};
+        use rand::rngs::StdRng;
+        use super::*;
+
+        #[test]
+        fn test_no_points() {
+            assert_eq!(BBox::<f64, 3>::bounding(&[]), None);
+        }
+
+        #[test]
+        fn test_single_point() {
+            let bbox = BBox::bounding(&[point![3, 2]]).unwrap();
+
+            assert_eq!(bbox, BBox::from(point![3, 2]..=point![3, 2]));
+            assert!(bbox.holds(&point![3, 2]));
+        }
+
+        #[test]
+        fn test_collinear_points() {
+            let points = [point![0.0, 1.0], point![2.0, 1.0], point![-1.5, 1.0]];
+
+            assert_eq!(BBox::bounding(&points), Some(BBox::from(point![-1.5, 1.0]..=point![2.0, 1.0])));
+        }
+
+        #[test]
+        fn test_random_points() {
+            let mut rng = StdRng::seed_from_u64(42);
+            let points: Vec<_> = (0..1000)
+                .map(|_| point![rng.gen_range(-100..100), rng.gen_range(-100..100), rng.gen_range(-100..100)])
+                .collect();
+
+            let bbox = BBox::bounding(&points).unwrap();
+
+            assert!(bbox.holds_all(points.iter().copied()));
+            assert!(bbox.is_closed());
+        }
+
+        #[test]
+        fn test_extend_with() {
+            let mut bbox = BBox::bounding(&[point![0, 0]]).unwrap();
+            bbox.extend_with([point![2, -1], point![1, 3]]);
+
+            assert_eq!(bbox, BBox::from(point![0, -1]..=point![2, 3]));
+
+            bbox.extend_with([]);
+
+            assert_eq!(bbox, BBox::from(point![0, -1]..=point![2, 3]));
+        }
+    }
+
+    mod include {
+        use na::point;
+        use super::*;
+
+        #[test]
+        fn test_point_inside() {
+            let bbox = BBox::from(point![0, 0]..point![5, 5]);
+
+            assert_eq!(bbox.include(point![2, 2]), bbox);
+        }
+
+        #[test]
+        fn test_point_outside() {
+            assert_eq!(
+                BBox::from(point![0, 0]..point![5, 5]).include(point![-2, 5]),
+                BBox::from([
+                    (Included(-2), Excluded(5)),
+                    (Included(0), Included(5)),
+                ])
+            );
+
+            assert_eq!(
+                BBox::from([(Excluded(0), Unbounded), (Unbounded, Excluded(5))]).include(point![0, 9]),
+                BBox::from([
+                    (Included(0), Unbounded),
+                    (Unbounded, Included(9)),
+                ])
+            );
+        }
+
+        #[test]
+        fn test_empty_bbox() {
+            assert_eq!(
+                BBox::from(point![5, 0]..point![0, 5]).include(point![3, 3]),
+                BBox::from(point![3, 3]..=point![3, 3])
+            );
+
+            let mut bbox = BBox::from([(Included(5), Excluded(0)), (Included(0), Excluded(100))]);
+            bbox.extend_with([point![3, 3], point![4, 1]]);
+
+            assert_eq!(bbox, BBox::from(point![3, 1]..=point![4, 3]));
+            assert!(!bbox.holds(&point![3, 50]));
+        }
+    }
+
+    mod union {
+        use na::point;
+        use super::*;
+
+        #[test]
+        fn test_union_holds_both() {
+            let a = BBox::from([(Excluded(0), Included(3)), (Unbounded, Excluded(2))]);
+            let b = BBox::from(point![2, -1]..=point![5, 4]);
+            let union = a.union(b);
+
+            assert_eq!(union, BBox::from([(Excluded(0), Included(5)), (Unbounded, Included(4))]));
+            assert!(union.contains_bbox(a));
+            assert!(union.holds(&b));
+        }
+
+        #[test]
+        fn test_union_assign_idempotent() {
+            let mut bbox = BBox::from(point![0, 0]..point![2, 2]);
+            let other = BBox::from(point![3, -1]..point![4, 1]);
+
+            bbox.union_assign(other);
+            let once = bbox;
+            bbox.union_assign(other);
+
+            assert_eq!(bbox, once);
+            assert_eq!(once.union(once), once);
+        }
+
+        #[test]
+        fn test_union_with_empty() {
+            let bbox = BBox::from(point![0, 0]..point![2, 2]);
+            let empty = BBox::from(point![5, 5]..point![3, 3]);
+
+            assert_eq!(bbox.union(empty), bbox);
+            assert_eq!(empty.union(bbox), bbox);
+            assert!(bbox.contains_bbox(empty));
+        }
+
+        #[test]
+        fn test_enlargement_for() {
+            let bbox = BBox::from(point![0.0, 0.0]..point![2.0, 2.0]);
+
+            assert_eq!(bbox.enlargement_for(&BBox::from(point![0.5, 0.5]..point![1.0, 2.0])), Some(0.0));
+            assert_eq!(bbox.enlargement_for(&BBox::from(point![3.0, 3.0]..point![4.0, 5.0])), Some(16.0));
+            assert_eq!(bbox.enlargement_for(&BBox::from(point![3.0, 3.0]..)), None);
+        }
+    }
+
+    mod identities {
+        use na::point;
+        use super::*;
+
+        fn samples() -> [BBox<i32, 2>; 5] {
+            [
+                BBox::from(point![0, 0]..point![5, 5]),
+                BBox::from(point![-3, 2]..=point![-1, 8]),
+                BBox::from([(Excluded(1), Unbounded), (Unbounded, Included(0))]),
+                BBox::from(point![3, 3]..point![1, 1]),
+                BBox::universe(),
+            ]
+        }
+
+        #[test]
+        fn test_empty() {
+            for pt in BBoxWalker::new(point![-2, -2], point![2, 2]).iter() {
+                assert!(!BBox::empty().holds(&pt), "{pt}");
+            }
+
+            assert!(BBox::<f64, 3>::empty().is_range_empty());
+            assert!(!BBox::<f64, 3>::empty().holds(&point![0.0, 0.0, 0.0]));
+        }
+
+        #[test]
+        fn test_union_identity() {
+            for bbox in samples().iter().filter(|bbox| !bbox.is_range_empty()) {
+                assert_eq!(bbox.union(BBox::empty()), *bbox);
+                assert_eq!(BBox::empty().union(bbox), *bbox);
+            }
+        }
+
+        #[test]
+        fn test_intersection_identities() {
+            for bbox in samples() {
+                assert_eq!(bbox.intersection(&BBox::universe()), bbox);
+                assert_eq!(BBox::universe().intersection(&bbox), bbox);
+                assert!(bbox.intersection(&BBox::empty()).is_range_empty());
+                assert!(BBox::empty().intersection(&bbox).is_range_empty());
+                assert!(!bbox.intersects(BBox::empty()));
+            }
+        }
+    }
+
+    mod intersection_commutativity {
+        use core::fmt::Debug;
+        use na::point;
+        use super::*;
+
+        fn assert_commutes<A, B>(a: &A, b: &B)
+        where
+            A: Debug + Intersection<B>,
+            B: Debug + Intersection<A>,
+            A::Output: Into<BBox<i32, 2>>,
+            B::Output: Into<BBox<i32, 2>>,
+        {
+            assert_eq!(a.intersection(b).into(), b.intersection(a).into(), "{a:?} & {b:?}");
+        }
+
+        macro_rules! assert_row {
+            ($lhs:expr; [$($rhs:expr),*]) => { $(assert_commutes(&$lhs, &$rhs);)* };
+        }
 
-            range.0 = lhs.0;
-            range.1 = min_bound(lhs.1, Included(unsafe { *rhs.end.get_unchecked(idx) }));
+        macro_rules! assert_matrix {
+            ([$($lhs:expr),*], $rhs:tt) => { $(assert_row!($lhs; $rhs);)* };
         }
 
-        BBox::from(ranges)
+        #[test]
+        fn test_all_kinds() {
+            let cases = [
+                (point![0, 0], point![5, 5]),
+                (point![0, 1], point![4, 6]),
+                (point![1, -1], point![5, 4]),
+                (point![2, 2], point![3, 3]),
+                (point![5, 5], point![5, 5]),
+            ];
+
+            for (ls, le) in cases {
+                for (rs, re) in cases {
+                    assert_matrix!([
+                        BBox::from([(Excluded(ls.x), Included(le.x)), (Included(ls.y), Unbounded)]),
+                        ls..le,
+                        ls..,
+                        ..,
+                        ls..=le,
+                        ..le,
+                        ..=le,
+                        (Excluded(ls), Included(le))
+                    ], [
+                        BBox::from([(Excluded(rs.x), Included(re.x)), (Included(rs.y), Unbounded)]),
+                        rs..re,
+                        rs..,
+                        ..,
+                        rs..=re,
+                        ..re,
+                        ..=re,
+                        (Excluded(rs), Included(re))
+                    ]);
+                }
+            }
+        }
     }
-}
-
-impl<N: Copy + PartialOrd + Scalar, const D: usize> Intersection<(Bound<Point<N, D>>, Bound<Point<N, D>>)> for BBox<N, D> {
-    type Output = BBox<N, D>;
 
-    fn intersection(&self, rhs: &(Bound<Point<N, D>>, Bound<Point<N, D>>)) -> Self::Output {
-        let mut ranges = [(Unbounded, Unbounded); D];
+    mod intersects {
+        use rand::{Rng, SeedableRng};
+        use rand::rngs::StdRng;
+        use super::*;
 
-        for (idx, range) in ranges.iter_mut().enumerate() {
-            let lhs = unsafe { self.get_unchecked(idx) };
-            let rhs = unsafe { rhs.get_bounds_unchecked(idx) };
+        fn random_bound(rng: &mut StdRng) -> Bound<i32> {
+            match rng.gen_range(0..3) {
+                0 => Included(rng.gen_range(-5..5)),
+                1 => Excluded(rng.gen_range(-5..5)),
+                _ => Unbounded,
+            }
+        }
 
-            range.0 = max_bound(lhs.0, rhs.0);
-            range.1 = min_bound(lhs.1, rhs.1);
+        fn random_bbox(rng: &mut StdRng) -> BBox<i32, 2> {
+            BBox::from(core::array::from_fn(|_| (random_bound(rng), random_bound(rng))))
         }
 
-        BBox::from(ranges)
-    }
-}
+        #[test]
+        fn test_intersects_matches_intersection() {
+            let mut rng = StdRng::seed_from_u64(42);
 
-impl<N, Rhs, const D: usize> Overlaps<Rhs> for BBox<N, D>
-where
-    N: Copy + PartialOrd + Scalar,
-    Rhs: DimBounds<N, D>,
-    <Rhs as DimBounds<N, D>>::Output: Overlaps<BBoxElement<N>>,
-{
-    fn overlaps(&self, rhs: &Rhs) -> bool {
-        self.ranges.iter().enumerate()
-            .all(|(idx, range)| unsafe { rhs.get_bounds_unchecked(idx) }.overlaps(range))
-    }
-}
+            for _ in 0..10_000 {
+                let (a, b) = (random_bbox(&mut rng), random_bbox(&mut rng));
 
-// Conversion
-impl<N: Scalar, const D: usize> AsRef<[BBoxElement<N>; D]> for BBox<N, D> {
-    #[inline]
-    fn as_ref(&self) -> &[BBoxElement<N>; D] {
-        &self.ranges
-    }
-}
+                assert_eq!(a.intersects(b), !a.intersection(&b).is_range_empty(), "{a:?} {b:?}");
+                assert_eq!(a.intersects(b), b.intersects(a), "{a:?} {b:?}");
+            }
+        }
 
-impl<N: Scalar, const D: usize> AsMut<[BBoxElement<N>; D]> for BBox<N, D> {
-    #[inline]
-    fn as_mut(&mut self) -> &mut [BBoxElement<N>; D] {
-        &mut self.ranges
-    }
-}
+        #[test]
+        fn test_intersect_assign() {
+            let mut rng = StdRng::seed_from_u64(42);
 
-/// Builds a bounding box from a set of ranges
-impl<N: Scalar, const D: usize> From<[BBoxElement<N>; D]> for BBox<N, D> {
-    fn from(ranges: [BBoxElement<N>; D]) -> Self {
-        BBox {
-            ranges
+            for _ in 0..1_000 {
+                let (mut a, b) = (random_bbox(&mut rng), random_bbox(&mut rng));
+                let expected = a.intersection(&b);
+
+                a.intersect_assign(b);
+                assert_eq!(a, expected);
+            }
         }
-    }
-}
 
-// Operators
-impl<N: Scalar, const D: usize> Index<usize> for BBox<N, D> {
-    type Output = BBoxElement<N>;
+        /// Run with `cargo test --release -- --ignored bench_intersects --nocapture`
+        #[cfg(feature = "std")]
+        #[test]
+        #[ignore]
+        fn bench_intersects() {
+            use std::hint::black_box;
+            use std::time::Instant;
 
-    #[inline]
-    fn index(&self, index: usize) -> &Self::Output {
-        &self.ranges[index]
-    }
-}
+            let mut rng = StdRng::seed_from_u64(42);
+            let pairs: Vec<_> = (0..1_000_000).map(|_| (random_bbox(&mut rng), random_bbox(&mut rng))).collect();
 
-impl<N: Scalar, const D: usize> IndexMut<usize> for BBox<N, D> {
-    #[inline]
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        &mut self.ranges[index]
-    }
-}
+            let start = Instant::now();
+            let count = pairs.iter().filter(|(a, b)| black_box(a).intersects(b)).count();
+            std::println!("intersects: {:?} ({count} hits)", start.elapsed());
 
-impl<N: Scalar, const D: usize> PartialEq for BBox<N, D> {
-    fn eq(&self, other: &Self) -> bool {
-        self.ranges == other.ranges
+            let start = Instant::now();
+            let count = pairs.iter().filter(|(a, b)| !black_box(a).intersection(b).is_range_empty()).count();
+            std::println!("intersection + is_range_empty: {:?} ({count} hits)", start.elapsed());
+        }
     }
-}
 
-// Tests
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    mod holds {
-        use na::point;
+    mod properties {
+        use na::Point2;
+        use proptest::prelude::*;
+        use crate::bbox::arbitrary::{bbox_strategy, BBoxParams};
         use super::*;
 
-        #[test]
-        fn test_all_point_coords_in_ranges() {
-            assert!(BBox::from(point![0, 0]..point![5, 5]).holds(&point![2, 2]));
+        fn small_bbox(params: BBoxParams) -> impl Strategy<Value = BBox<i32, 2>> {
+            bbox_strategy(-8..8, params)
         }
 
-        #[test]
-        fn test_some_point_coords_lower_than_start() {
-            assert!(!BBox::from(point![0, 0]..point![5, 5]).holds(&point![-2, 2]));
-            assert!(!BBox::from(point![0, 0]..point![5, 5]).holds(&point![2, -2]));
-        }
+        proptest! {
+            #[test]
+            fn intersection_commutes(a in any::<BBox<i32, 2>>(), b in any::<BBox<i32, 2>>()) {
+                prop_assert_eq!(a.intersection(&b), b.intersection(&a));
+            }
 
-        #[test]
-        fn test_some_point_coords_greater_than_end() {
-            assert!(!BBox::from(point![0, 0]..point![5, 5]).holds(&point![7, 2]));
-            assert!(!BBox::from(point![0, 0]..point![5, 5]).holds(&point![2, 7]));
+            #[test]
+            fn intersection_holds_points_of_both(
+                a in small_bbox(BBoxParams { allow_unbounded: true, allow_empty: true }),
+                b in small_bbox(BBoxParams { allow_unbounded: true, allow_empty: true }),
+                x in -10..10, y in -10..10,
+            ) {
+                let pt = Point2::new(x, y);
+
+                prop_assert_eq!(a.intersection(&b).holds(&pt), a.holds(&pt) && b.holds(&pt));
+                prop_assert_eq!(a.intersects(b), !a.intersection(&b).is_range_empty());
+            }
+
+            #[test]
+            fn union_holds_both(a in small_bbox(BBoxParams::default()), b in small_bbox(BBoxParams::default())) {
+                let union = a.union(b);
+
+                prop_assert!(union.contains_bbox(a));
+                prop_assert!(union.contains_bbox(b));
+            }
+
+            #[test]
+            fn walker_count_matches_lattice(bbox in small_bbox(BBoxParams { allow_unbounded: false, allow_empty: true })) {
+                let lattice = BBoxWalker::new(Point2::new(-8, -8), Point2::new(8, 8)).iter()
+                    .filter(|pt| bbox.holds(pt))
+                    .count();
+
+                prop_assert_eq!(bbox.walk().unwrap().point_count(), Some(lattice));
+            }
         }
     }
 
@@ -599,6 +3566,218 @@ mod tests {
         }
     }
 
+    mod partially_unbounded {
+        use na::point;
+        use super::*;
+
+        #[test]
+        fn test_start_point_or() {
+            let bbox = BBox::from([(Excluded(3), Included(8)), (Unbounded, Excluded(5))]);
+
+            assert_eq!(bbox.start_point_or(&point![0, 0]), point![3, 0]);
+            assert_eq!(bbox.end_point_or(&point![0, 0]), point![8, 5]);
+        }
+
+        #[test]
+        fn test_bounded_bbox() {
+            let bbox = BBox::from(point![1, 2]..point![3, 4]);
+
+            assert_eq!(Some(bbox.start_point_or(&point![0, 0])), bbox.start_point());
+            assert_eq!(Some(bbox.end_point_or(&point![0, 0])), bbox.end_point());
+        }
+
+        #[test]
+        fn test_clamp_unbounded_bbox() {
+            let viewport = BBox::from(point![0, 0]..point![10, 10]);
+
+            assert_eq!(BBox::default().clamped_to(viewport), viewport);
+        }
+
+        #[test]
+        fn test_clamp_half_unbounded_bbox() {
+            let viewport = BBox::from(point![0, 0]..=point![10, 10]);
+            let clamped = BBox::from(..point![5, 20]).clamped_to(viewport);
+
+            assert_eq!(
+                clamped,
+                BBox::from([(Included(0), Excluded(5)), (Included(0), Included(10))])
+            );
+            assert!(clamped.is_bounded());
+        }
+    }
+
+    mod extend {
+        use na::point;
+        use super::*;
+
+        #[test]
+        fn test_extend_empty_with_points() {
+            let points = [point![1, 4], point![3, 2], point![2, 5]];
+            let mut bbox = BBox::empty();
+            bbox.extend(points);
+
+            assert_eq!(Some(bbox), BBox::bounding(&points));
+
+            let mut bbox = BBox::empty();
+            bbox.extend(points.iter());
+
+            assert_eq!(Some(bbox), BBox::bounding(&points));
+        }
+
+        #[test]
+        fn test_extend_universe() {
+            let mut bbox = BBox::universe();
+            bbox.extend([point![1, 4], point![3, 2]]);
+            bbox.extend([BBox::from(point![0, 0]..point![2, 2])]);
+
+            assert_eq!(bbox, BBox::universe());
+        }
+
+        #[test]
+        fn test_extend_nothing() {
+            let start = BBox::from([(Excluded(0), Included(5)), (Unbounded, Excluded(5))]);
+            let mut bbox = start;
+
+            bbox.extend(Vec::<Point<i32, 2>>::new());
+            bbox.extend(Vec::<BBox<i32, 2>>::new());
+
+            assert_eq!(bbox, start);
+        }
+
+        #[test]
+        fn test_extend_upgrades_excluded() {
+            let mut bbox = BBox::from([(Excluded(0), Excluded(5)), (Unbounded, Excluded(5))]);
+            bbox.extend([point![0, 5], point![-1, 2]]);
+
+            assert_eq!(bbox, BBox::from([(Included(-1), Excluded(5)), (Unbounded, Included(5))]));
+        }
+
+        #[test]
+        fn test_extend_with_boxes() {
+            let mut bbox = BBox::empty();
+            bbox.extend([
+                BBox::from(point![0, 0]..point![2, 2]),
+                BBox::empty(),
+                BBox::from(point![1, 3]..=point![4, 5]),
+            ]);
+
+            assert_eq!(bbox, BBox::from([(Included(0), Included(4)), (Included(0), Included(5))]));
+        }
+    }
+
+    mod fraction {
+        use na::{point, vector};
+        use super::*;
+
+        #[test]
+        fn test_point_at_fraction() {
+            let bbox = BBox::from([(Included(-2.0), Excluded(2.0)), (Excluded(1.0), Included(5.0))]);
+
+            assert_eq!(bbox.point_at_fraction(&vector![0.0, 0.0]), Some(point![-2.0, 1.0]));
+            assert_eq!(bbox.point_at_fraction(&vector![0.5, 0.5]), Some(point![0.0, 3.0]));
+            assert_eq!(bbox.point_at_fraction(&vector![1.0, 1.0]), Some(point![2.0, 5.0]));
+            assert_eq!(bbox.point_at_fraction(&vector![1.5, -0.25]), Some(point![4.0, 0.0]));
+        }
+
+        #[test]
+        fn test_fraction_round_trip() {
+            let bbox = BBox::from(point![-1.5, 0.25, 3.0]..point![7.25, 2.0, 11.0]);
+
+            for fractions in [vector![0.0, 0.5, 1.0], vector![0.3, 0.8, 0.1], vector![-0.7, 1.9, 0.333]] {
+                let point = bbox.point_at_fraction(&fractions).unwrap();
+                let res = bbox.fraction_of_point(&point).unwrap();
+
+                assert!((res - fractions).amax() < 1e-12, "{res} != {fractions}");
+            }
+        }
+
+        #[test]
+        fn test_invalid_bbox() {
+            let flat = BBox::from(point![0.0, 1.0]..=point![5.0, 1.0]);
+
+            assert_eq!(flat.point_at_fraction(&vector![0.5, 0.5]), None);
+            assert_eq!(flat.fraction_of_point(&point![1.0, 1.0]), None);
+            assert_eq!(BBox::from(point![0.0, 0.0]..).point_at_fraction(&vector![0.5, 0.5]), None);
+            assert_eq!(BBox::from(point![5.0, 0.0]..point![0.0, 5.0]).fraction_of_point(&point![1.0, 1.0]), None);
+        }
+    }
+
+    mod mapping {
+        use na::{point, Point2, Point3};
+        use super::*;
+
+        #[test]
+        fn test_map_corners() {
+            let source = BBox::from([(Excluded(-1.0), Included(1.0)), (Included(0.0), Excluded(4.0))]);
+            let target = BBox::from(point![0.0, 100.0]..point![200.0, 0.0]);
+
+            for (from, to) in source.corners().unwrap().zip(target.corners().unwrap()) {
+                assert_eq!(source.map_point_to(&target, &from), Some(to));
+            }
+        }
+
+        #[test]
+        fn test_2d_mapping_agrees() {
+            let source = BBox::from(point![-1.0, 0.0]..point![1.0, 4.0]);
+            let target = BBox::from(point![0.0, 100.0]..point![200.0, 0.0]);
+            let mapping = source.mapping_to(&target).unwrap();
+
+            for pt in [point![0.0, 2.0], point![-0.5, 3.0], point![1.5, -1.0], point![0.25, 0.125]] {
+                let expected = source.map_point_to(&target, &pt).unwrap();
+                let mapped: Point2<f64> = mapping.transform_point(&pt);
+
+                assert!((mapped - expected).norm() < 1e-9, "{mapped} != {expected}");
+            }
+        }
+
+        #[test]
+        fn test_3d_mapping_agrees() {
+            let source = BBox::from(point![-1.0, 0.0, 2.0]..point![1.0, 4.0, 3.0]);
+            let target = BBox::from(point![0.0, 0.0, 0.0]..point![1.0, 1.0, 1.0]);
+            let mapping = source.mapping_to(&target).unwrap();
+
+            for pt in [point![0.0, 2.0, 2.5], point![-0.5, 3.0, 2.0], point![1.5, -1.0, 4.0]] {
+                let expected = source.map_point_to(&target, &pt).unwrap();
+                let mapped: Point3<f64> = mapping.transform_point(&pt);
+
+                assert!((mapped - expected).norm() < 1e-9, "{mapped} != {expected}");
+            }
+        }
+
+        #[test]
+        fn test_degenerate_boxes() {
+            let source = BBox::from(point![0.0, 0.0]..point![1.0, 1.0]);
+
+            assert_eq!(source.map_point_to(&BBox::from(point![0.0, 0.0]..), &point![0.5, 0.5]), None);
+            assert_eq!(source.map_point_to(&BBox::from(point![0.0, 0.0]..=point![0.0, 1.0]), &point![0.5, 0.5]), None);
+            assert_eq!(BBox::from(point![0.0, 0.0]..=point![0.0, 1.0]).mapping_to(&source), None);
+        }
+    }
+
+    mod map_scalar {
+        use na::point;
+        use super::*;
+
+        #[test]
+        fn test_round_trip() {
+            let bbox = BBox::from([(Included(-3), Excluded(7)), (Excluded(2), Included(5)), (Unbounded, Included(-1))]);
+            let real = bbox.map_scalar(|x| x as f64 / 3.0);
+
+            assert_eq!(real.map_scalar(|x| (x * 3.0).round() as i32), bbox);
+        }
+
+        #[test]
+        fn test_keeps_bound_kinds() {
+            let bbox = BBox::from([(Excluded(1), Unbounded), (Unbounded, Included(4))]);
+
+            assert_eq!(
+                bbox.map_scalar(|x| x * 10),
+                BBox::from([(Excluded(10), Unbounded), (Unbounded, Included(40))])
+            );
+            assert_eq!(BBox::from(point![1, 2]..).map_scalar(f64::from), BBox::from(point![1.0, 2.0]..));
+        }
+    }
+
     mod overlaps {
         use na::point;
         use super::*;
@@ -614,6 +3793,52 @@ mod tests {
         }
     }
 
+    mod try_to_point_range {
+        use na::point;
+        use super::*;
+
+        #[test]
+        fn test_range_round_trip() {
+            let range = point![0, 1]..point![5, 6];
+
+            assert_eq!(
+                BBox::from(range.clone()).try_to_point_range(),
+                Some((Included(range.start), Excluded(range.end)))
+            );
+            assert_eq!(BBox::from(..=point![5, 6]).try_to_point_range(), Some((Unbounded, Included(point![5, 6]))));
+            assert_eq!(BBox::<i32, 2>::from(..).try_to_point_range(), Some((Unbounded, Unbounded)));
+        }
+
+        #[test]
+        fn test_mixed_kinds() {
+            assert_eq!(BBox::from([(Included(0), Excluded(5)), (Excluded(0), Excluded(5))]).try_to_point_range(), None);
+            assert_eq!(BBox::from([(Included(0), Excluded(5)), (Included(0), Unbounded)]).try_to_point_range(), None);
+        }
+    }
+
+    mod walk_within {
+        use na::point;
+        use super::*;
+
+        #[test]
+        fn test_excluded_clamp_edges() {
+            let clamp = BBox::from([(Excluded(0), Excluded(3)), (Included(0), Excluded(2))]);
+            let walker = BBox::from(point![0, 0]..).walk_within(clamp).unwrap();
+
+            assert_eq!(
+                walker.iter().collect::<Vec<_>>(),
+                vec![point![1, 0], point![1, 1], point![2, 0], point![2, 1]]
+            );
+        }
+
+        #[test]
+        fn test_disjoint_clamp() {
+            let clamp = BBox::from(point![-3, -3]..point![0, 0]);
+
+            assert!(BBox::from(point![0, 0]..).walk_within(clamp).is_none());
+        }
+    }
+
     mod walkable {
         use na::point;
         use super::*;
@@ -653,5 +3878,43 @@ mod tests {
                 None
             );
         }
+
+        #[test]
+        fn test_iter_points() {
+            assert_eq!(
+                BBox::from(point![0, 0]..=point![2, 2]).iter_points().unwrap().count(),
+                9
+            );
+
+            assert!(BBox::from(point![0, 0]..).iter_points().is_none());
+        }
+
+        #[test]
+        fn test_walk_numeric_limits() {
+            let bbox = BBox::from([(Excluded(0u8), Included(255)), (Included(254), Included(255))]);
+            let walker = bbox.walk().unwrap();
+
+            assert_eq!(walker.first(), &point![1, 254]);
+            assert_eq!(walker.last(), &point![255, 255]);
+            assert_eq!(walker.iter().count(), 510);
+            assert_eq!(walker.iter().last(), Some(point![255, 255]));
+
+            let bbox = BBox::from([(Included(i32::MAX - 1), Included(i32::MAX))]);
+
+            assert_eq!(bbox.iter_points().unwrap().collect::<Vec<_>>(), vec![point![i32::MAX - 1], point![i32::MAX]]);
+        }
+
+        #[test]
+        fn test_excluded_at_numeric_limits() {
+            let bbox = BBox::from([(Excluded(255u8), Included(255)), (Included(0), Included(1))]);
+
+            assert_eq!(bbox.first_point(), None);
+            assert!(bbox.iter_points().is_none());
+
+            let bbox = BBox::from([(Included(0u8), Excluded(0)), (Included(0), Included(1))]);
+
+            assert_eq!(bbox.last_point(), None);
+            assert!(bbox.iter_points().is_none());
+        }
     }
 }
@@ -0,0 +1,88 @@
+use core::fmt::Debug;
+use core::ops::Bound::{self, Excluded, Included, Unbounded};
+use na::Scalar;
+use proptest::arbitrary::{any, Arbitrary};
+use proptest::strategy::{BoxedStrategy, Strategy};
+
+use crate::BBox;
+
+/// Parameters of the bbox strategies
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BBoxParams {
+    /// Allows unbounded bounds
+    pub allow_unbounded: bool,
+
+    /// Allows boxes with reversed or empty axes
+    pub allow_empty: bool,
+}
+
+/// Default parameters allow unbounded bounds, but no empty box
+impl Default for BBoxParams {
+    fn default() -> Self {
+        BBoxParams {
+            allow_unbounded: true,
+            allow_empty: false,
+        }
+    }
+}
+
+/// Builds a strategy generating bboxes which bound values are drawn from element, with random bound kinds.
+/// Shrinks bound values as element does, and bound kinds toward included.
+///
+/// # Example
+/// ```
+/// use proptest::strategy::{Strategy, ValueTree};
+/// use proptest::test_runner::TestRunner;
+/// use pythagore::{BBox, IsRangeEmpty};
+/// use pythagore::bbox::{bbox_strategy, BBoxParams};
+///
+/// let strategy = bbox_strategy::<_, _, 2>(-10..10, BBoxParams::default());
+/// let bbox: BBox<i32, 2> = strategy.new_tree(&mut TestRunner::default()).unwrap().current();
+///
+/// assert!(!bbox.is_range_empty());
+/// ```
+pub fn bbox_strategy<N, S, const D: usize>(element: S, params: BBoxParams) -> impl Strategy<Value = BBox<N, D>>
+where
+    N: Copy + Debug + PartialOrd + Scalar,
+    S: Clone + Strategy<Value = N>,
+{
+    let kinds: u8 = if params.allow_unbounded { 3 } else { 2 };
+    let axis = (element.clone(), element, 0..kinds, 0..kinds)
+        .prop_map(move |(a, b, start, end)| {
+            if params.allow_empty {
+                (make_bound(a, start), make_bound(b, end))
+            } else if a == b {
+                // Only included bounds can hold a single value
+                (Included(a), Included(b))
+            } else if a < b {
+                (make_bound(a, start), make_bound(b, end))
+            } else {
+                (make_bound(b, start), make_bound(a, end))
+            }
+        });
+
+    proptest::array::uniform::<_, D>(axis).prop_map(BBox::from)
+}
+
+/// Generates bboxes with arbitrary bound values, see [`bbox_strategy`]
+impl<N, const D: usize> Arbitrary for BBox<N, D>
+where
+    N: Arbitrary + Copy + Debug + PartialOrd + Scalar,
+    N::Strategy: Clone + 'static,
+{
+    type Parameters = BBoxParams;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(params: Self::Parameters) -> Self::Strategy {
+        bbox_strategy(any::<N>(), params).boxed()
+    }
+}
+
+/// Builds bound of given kind: 0 is included, 1 excluded and 2 unbounded
+fn make_bound<N>(x: N, kind: u8) -> Bound<N> {
+    match kind {
+        0 => Included(x),
+        1 => Excluded(x),
+        _ => Unbounded,
+    }
+}
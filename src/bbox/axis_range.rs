@@ -0,0 +1,194 @@
+use core::ops::Bound::{self, Excluded, Included, Unbounded};
+use core::ops::{Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+use na::Scalar;
+
+use crate::BBox;
+
+/// Range of a bbox along one axis, as any of the std range types.
+/// Allows mixing range types when building a bbox with [`BBox::from_ranges`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum AxisRange<N> {
+    Range(Range<N>),
+    RangeFrom(RangeFrom<N>),
+    RangeFull,
+    RangeInclusive(RangeInclusive<N>),
+    RangeTo(RangeTo<N>),
+    RangeToInclusive(RangeToInclusive<N>),
+
+    /// Bounds not matching any std range type, like an excluded start
+    Bounds((Bound<N>, Bound<N>)),
+}
+
+impl<N: Copy + Scalar, const D: usize> BBox<N, D> {
+    /// Builds a bbox from one range per axis. Ranges of different types can be mixed using [`AxisRange`].
+    ///
+    /// # Example
+    /// ```
+    /// use std::ops::Bound::{Excluded, Included, Unbounded};
+    /// use pythagore::{AxisRange, BBox};
+    ///
+    /// assert_eq!(
+    ///     BBox::from_ranges([AxisRange::from(0..5), AxisRange::from(2..=8), AxisRange::from(..)]),
+    ///     BBox::from([(Included(0), Excluded(5)), (Included(2), Included(8)), (Unbounded, Unbounded)])
+    /// );
+    /// ```
+    pub fn from_ranges<R: RangeBounds<N>>(ranges: [R; D]) -> BBox<N, D> {
+        let mut bbox = BBox::default();
+
+        for (idx, range) in ranges.iter().enumerate() {
+            bbox[idx] = (range.start_bound().cloned(), range.end_bound().cloned());
+        }
+
+        bbox
+    }
+
+    /// Returns range of bbox along given axis, using the matching std range type
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::{AxisRange, BBox};
+    ///
+    /// assert_eq!(BBox::from(point![1, 2]..point![3, 4]).axis_range(1), AxisRange::Range(2..4));
+    /// ```
+    #[inline]
+    pub fn axis_range(&self, idx: usize) -> AxisRange<N> {
+        AxisRange::from(self[idx])
+    }
+}
+
+impl<N> RangeBounds<N> for AxisRange<N> {
+    fn start_bound(&self) -> Bound<&N> {
+        match self {
+            AxisRange::Range(range) => range.start_bound(),
+            AxisRange::RangeFrom(range) => range.start_bound(),
+            AxisRange::RangeFull => Unbounded,
+            AxisRange::RangeInclusive(range) => range.start_bound(),
+            AxisRange::RangeTo(range) => range.start_bound(),
+            AxisRange::RangeToInclusive(range) => range.start_bound(),
+            AxisRange::Bounds(range) => range.0.as_ref(),
+        }
+    }
+
+    fn end_bound(&self) -> Bound<&N> {
+        match self {
+            AxisRange::Range(range) => range.end_bound(),
+            AxisRange::RangeFrom(range) => range.end_bound(),
+            AxisRange::RangeFull => Unbounded,
+            AxisRange::RangeInclusive(range) => range.end_bound(),
+            AxisRange::RangeTo(range) => range.end_bound(),
+            AxisRange::RangeToInclusive(range) => range.end_bound(),
+            AxisRange::Bounds(range) => range.1.as_ref(),
+        }
+    }
+}
+
+// Conversion
+impl<N> From<Range<N>> for AxisRange<N> {
+    #[inline]
+    fn from(value: Range<N>) -> Self {
+        AxisRange::Range(value)
+    }
+}
+
+impl<N> From<RangeFrom<N>> for AxisRange<N> {
+    #[inline]
+    fn from(value: RangeFrom<N>) -> Self {
+        AxisRange::RangeFrom(value)
+    }
+}
+
+impl<N> From<RangeFull> for AxisRange<N> {
+    #[inline]
+    fn from(_: RangeFull) -> Self {
+        AxisRange::RangeFull
+    }
+}
+
+impl<N> From<RangeInclusive<N>> for AxisRange<N> {
+    #[inline]
+    fn from(value: RangeInclusive<N>) -> Self {
+        AxisRange::RangeInclusive(value)
+    }
+}
+
+impl<N> From<RangeTo<N>> for AxisRange<N> {
+    #[inline]
+    fn from(value: RangeTo<N>) -> Self {
+        AxisRange::RangeTo(value)
+    }
+}
+
+impl<N> From<RangeToInclusive<N>> for AxisRange<N> {
+    #[inline]
+    fn from(value: RangeToInclusive<N>) -> Self {
+        AxisRange::RangeToInclusive(value)
+    }
+}
+
+/// Uses the std range type matching given bounds, if any
+impl<N> From<(Bound<N>, Bound<N>)> for AxisRange<N> {
+    fn from(value: (Bound<N>, Bound<N>)) -> Self {
+        match value {
+            (Included(start), Excluded(end)) => AxisRange::Range(start..end),
+            (Included(start), Unbounded) => AxisRange::RangeFrom(start..),
+            (Unbounded, Unbounded) => AxisRange::RangeFull,
+            (Included(start), Included(end)) => AxisRange::RangeInclusive(start..=end),
+            (Unbounded, Excluded(end)) => AxisRange::RangeTo(..end),
+            (Unbounded, Included(end)) => AxisRange::RangeToInclusive(..=end),
+            bounds => AxisRange::Bounds(bounds),
+        }
+    }
+}
+
+impl<N: Clone> From<AxisRange<N>> for (Bound<N>, Bound<N>) {
+    #[inline]
+    fn from(value: AxisRange<N>) -> Self {
+        (value.start_bound().cloned(), value.end_bound().cloned())
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use na::point;
+    use super::*;
+
+    #[test]
+    fn test_from_ranges() {
+        assert_eq!(
+            BBox::from_ranges([AxisRange::from(..=3), (1..).into(), (Excluded(2), Included(4)).into()]),
+            BBox::from([(Unbounded, Included(3)), (Included(1), Unbounded), (Excluded(2), Included(4))])
+        );
+        assert_eq!(BBox::from_ranges([0..5, 2..8]), BBox::from(point![0, 2]..point![5, 8]));
+    }
+
+    #[test]
+    fn test_axis_range() {
+        let bbox = BBox::from([
+            (Included(0), Excluded(5)),
+            (Included(0), Unbounded),
+            (Unbounded, Unbounded),
+            (Included(0), Included(5)),
+            (Unbounded, Excluded(5)),
+            (Unbounded, Included(5)),
+            (Excluded(0), Excluded(5)),
+        ]);
+
+        assert_eq!(bbox.axis_range(0), AxisRange::Range(0..5));
+        assert_eq!(bbox.axis_range(1), AxisRange::RangeFrom(0..));
+        assert_eq!(bbox.axis_range(2), AxisRange::RangeFull);
+        assert_eq!(bbox.axis_range(3), AxisRange::RangeInclusive(0..=5));
+        assert_eq!(bbox.axis_range(4), AxisRange::RangeTo(..5));
+        assert_eq!(bbox.axis_range(5), AxisRange::RangeToInclusive(..=5));
+        assert_eq!(bbox.axis_range(6), AxisRange::Bounds((Excluded(0), Excluded(5))));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let bbox = BBox::from([(Excluded(-1), Included(2)), (Unbounded, Excluded(4)), (Included(3), Included(3))]);
+        let ranges: [AxisRange<i32>; 3] = core::array::from_fn(|idx| bbox.axis_range(idx));
+
+        assert_eq!(BBox::from_ranges(ranges), bbox);
+    }
+}
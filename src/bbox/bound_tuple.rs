@@ -1,10 +1,10 @@
-use std::ops::Bound::{self, Excluded, Included, Unbounded};
-use std::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
-use na::{ClosedAdd, ClosedSub, Point, Scalar, SVector};
-use num_traits::One;
+use core::ops::Bound::{self, Excluded, Included, Unbounded};
+use core::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+use na::{Point, Scalar};
+use num_traits::{CheckedAdd, CheckedSub, One};
 
 use crate::{BBox, Intersection, PointBounds, Walkable};
-use crate::bbox::utils::{max_bound, min_bound};
+use crate::bbox::utils::{max_bound, min_bound, next_lattice_point, prev_lattice_point};
 use crate::traits::DimBounds;
 
 /// Builds a bounding box from a range of points
@@ -35,6 +35,27 @@ impl<N: Copy + Scalar, const D: usize> From<(Bound<Point<N, D>>, Bound<Point<N,
     }
 }
 
+/// Converts a bounding box back into a range of points, giving it back if it mixes bound kinds
+///
+/// # Example
+/// ```
+/// use std::ops::Bound::{self, Excluded, Included};
+/// use nalgebra::{point, Point2};
+/// use pythagore::BBox;
+///
+/// let range: Result<(Bound<Point2<i32>>, Bound<Point2<i32>>), _> = BBox::from(point![1, 2]..=point![3, 4]).try_into();
+///
+/// assert_eq!(range, Ok((Included(point![1, 2]), Included(point![3, 4]))));
+/// ```
+impl<N: Copy + Scalar, const D: usize> TryFrom<BBox<N, D>> for (Bound<Point<N, D>>, Bound<Point<N, D>>) {
+    type Error = BBox<N, D>;
+
+    #[inline]
+    fn try_from(value: BBox<N, D>) -> Result<Self, Self::Error> {
+        value.try_to_point_range().ok_or(value)
+    }
+}
+
 #[cfg(not(feature = "bound_map"))]
 impl<N: Copy + Scalar, const D: usize> DimBounds<N, D> for (Bound<Point<N, D>>, Bound<Point<N, D>>) {
     type Output = (Bound<N>, Bound<N>);
@@ -84,11 +105,13 @@ impl<N: Copy + Scalar, const D: usize> PointBounds<N, D> for (Bound<Point<N, D>>
     }
 }
 
-impl<N: ClosedAdd + ClosedSub + Copy + One + Scalar, const D: usize> Walkable<N, D> for (Bound<Point<N, D>>, Bound<Point<N, D>>) {
+/// Excluded bounds are moved to the nearest included lattice point. If that would overflow, first or
+/// last point is None.
+impl<N: CheckedAdd + CheckedSub + Copy + One + Scalar, const D: usize> Walkable<N, D> for (Bound<Point<N, D>>, Bound<Point<N, D>>) {
     fn first_point(&self) -> Option<Point<N, D>> {
         match self.0 {
             Included(pt) => Some(pt),
-            Excluded(pt) => Some(pt + SVector::repeat(N::one())),
+            Excluded(pt) => next_lattice_point(&pt),
             Unbounded => None
         }
     }
@@ -96,7 +119,7 @@ impl<N: ClosedAdd + ClosedSub + Copy + One + Scalar, const D: usize> Walkable<N,
     fn last_point(&self) -> Option<Point<N, D>> {
         match self.1 {
             Included(pt) => Some(pt),
-            Excluded(pt) => Some(pt - SVector::repeat(N::one())),
+            Excluded(pt) => prev_lattice_point(&pt),
             Unbounded => None
         }
     }
@@ -187,6 +210,25 @@ impl<N: Copy + PartialOrd + Scalar, const D: usize> Intersection for (Bound<Poin
 mod tests {
     use super::*;
 
+    mod conversion {
+        use na::point;
+        use super::*;
+
+        #[test]
+        fn test_try_from_bbox() {
+            let range = (Excluded(point![1, 2]), Included(point![3, 4]));
+
+            assert_eq!(<(Bound<_>, Bound<_>)>::try_from(BBox::from(range)), Ok(range));
+        }
+
+        #[test]
+        fn test_try_from_mixed_bbox() {
+            let bbox = BBox::from([(Included(1), Excluded(3)), (Excluded(2), Excluded(4))]);
+
+            assert_eq!(<(Bound<_>, Bound<_>)>::try_from(bbox), Err(bbox));
+        }
+    }
+
     mod dimension_bounds {
         use na::point;
         use super::*;
@@ -195,11 +237,15 @@ mod tests {
         fn test_get_bounds() {
             assert_eq!(
                 (Excluded(point![1, 2]), Excluded(point![3, 4])).get_bounds(0),
-                (Excluded(1), Excluded(3)),
+                Some((Excluded(1), Excluded(3))),
             );
             assert_eq!(
                 (Excluded(point![1, 2]), Excluded(point![3, 4])).get_bounds(1),
-                (Excluded(2), Excluded(4)),
+                Some((Excluded(2), Excluded(4))),
+            );
+            assert_eq!(
+                (Excluded(point![1, 2]), Excluded(point![3, 4])).get_bounds(2),
+                None,
             );
         }
     }
@@ -273,6 +319,25 @@ mod tests {
                 (Included(point![0, 0]), Unbounded).last_point(),
                 None
             );
+
+            assert_eq!(
+                (Excluded(point![0u8, 0]), Excluded(point![0u8, 5])).last_point(),
+                None
+            );
+            assert_eq!(
+                (Excluded(point![i8::MAX, 0]), Unbounded).first_point(),
+                None
+            );
+        }
+
+        #[test]
+        fn test_iter_points() {
+            assert_eq!(
+                (Excluded(point![0, 0]), Included(point![2, 2])).iter_points().unwrap().collect::<Vec<_>>(),
+                vec![point![1, 1], point![1, 2], point![2, 1], point![2, 2]]
+            );
+
+            assert!((Unbounded, Included(point![2, 2])).iter_points().is_none());
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,144 @@
+use core::ops::Bound::Included;
+use na::{Point, Scalar};
+
+use crate::{BBox, IsRangeEmpty};
+
+/// Builds the smallest bounding box holding all added points and bounding boxes
+///
+/// # Example
+/// ```
+/// use nalgebra::point;
+/// use pythagore::{BBox, BBoxBuilder};
+///
+/// let points = [point![1, 4], point![3, 2], point![2, 5]];
+/// let bbox = points.iter()
+///     .fold(BBoxBuilder::new(), |mut builder, pt| { builder.add_point(pt); builder })
+///     .build();
+///
+/// assert_eq!(bbox, Some(BBox::from(point![1, 2]..=point![3, 5])));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct BBoxBuilder<N: Scalar, const D: usize> {
+    bbox: Option<BBox<N, D>>,
+}
+
+impl<N: Copy + PartialOrd + Scalar, const D: usize> BBoxBuilder<N, D> {
+    /// Creates an empty builder
+    pub fn new() -> BBoxBuilder<N, D> {
+        BBoxBuilder { bbox: None }
+    }
+
+    /// Grows the bounding box to hold given point
+    pub fn add_point(&mut self, point: &Point<N, D>) {
+        self.bbox = Some(match &self.bbox {
+            Some(bbox) => bbox.include(point),
+            None => BBox::from(core::array::from_fn(|idx| (Included(point[idx]), Included(point[idx])))),
+        });
+    }
+
+    /// Grows the bounding box to hold given bounding box. Empty bounding boxes are ignored.
+    pub fn add_bbox(&mut self, other: &BBox<N, D>) {
+        if other.is_range_empty() {
+            return;
+        }
+
+        match &mut self.bbox {
+            Some(bbox) => bbox.union_assign(other),
+            None => self.bbox = Some(*other),
+        }
+    }
+
+    /// Grows the bounding box to hold given range
+    pub fn add_range(&mut self, range: impl Into<BBox<N, D>>) {
+        self.add_bbox(&range.into());
+    }
+
+    /// Returns the built bounding box, None if nothing was added
+    pub fn build(self) -> Option<BBox<N, D>> {
+        self.bbox
+    }
+}
+
+impl<N: Copy + PartialOrd + Scalar, const D: usize> Default for BBoxBuilder<N, D> {
+    #[inline]
+    fn default() -> Self {
+        BBoxBuilder::new()
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use core::ops::Bound::{Excluded, Unbounded};
+    use na::point;
+    use crate::{BBoxWalker, Holds};
+    use super::*;
+
+    #[test]
+    fn test_nothing_added() {
+        assert_eq!(BBoxBuilder::<i32, 2>::new().build(), None);
+    }
+
+    #[test]
+    fn test_add_point() {
+        let mut builder = BBoxBuilder::new();
+        builder.add_point(&point![3, 2]);
+
+        assert_eq!(builder.build(), Some(BBox::from(point![3, 2]..=point![3, 2])));
+    }
+
+    #[test]
+    fn test_add_disjoint_bboxes_and_point() {
+        let mut builder = BBoxBuilder::new();
+        builder.add_bbox(&BBox::from(point![0, 0]..point![2, 2]));
+        builder.add_range(point![5, 1]..=point![6, 3]);
+        builder.add_point(&point![3, -1]);
+
+        let bbox = builder.build().unwrap();
+
+        assert_eq!(
+            bbox,
+            BBox::from([
+                (Included(0), Included(6)),
+                (Included(-1), Included(3)),
+            ])
+        );
+
+        for pt in BBoxWalker::new(point![-2, -3], point![8, 5]).iter() {
+            let added = BBox::from(point![0, 0]..point![2, 2]).holds(&pt)
+                || BBox::from(point![5, 1]..=point![6, 3]).holds(&pt)
+                || pt == point![3, -1];
+
+            assert!(!added || bbox.holds(&pt), "{pt} should be held");
+            assert!(bbox.holds(&pt) == (pt.x >= 0 && pt.x <= 6 && pt.y >= -1 && pt.y <= 3), "{pt}");
+        }
+    }
+
+    #[test]
+    fn test_add_unbounded_range() {
+        let mut builder = BBoxBuilder::new();
+        builder.add_point(&point![3, 2]);
+        builder.add_range(..point![1, 5]);
+
+        assert_eq!(
+            builder.build(),
+            Some(BBox::from([
+                (Unbounded, Included(3)),
+                (Unbounded, Excluded(5)),
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_add_empty_bbox() {
+        let mut builder = BBoxBuilder::new();
+        builder.add_bbox(&BBox::from(point![5, 5]..point![0, 0]));
+
+        assert_eq!(builder.build(), None);
+
+        builder.add_point(&point![3, 2]);
+        builder.add_bbox(&BBox::from(point![5, 5]..point![0, 0]));
+
+        assert_eq!(builder.build(), Some(BBox::from(point![3, 2]..=point![3, 2])));
+    }
+}
@@ -0,0 +1,87 @@
+use core::iter::FusedIterator;
+use na::{Point, Scalar};
+
+/// Iterator over the corners of a bounded bbox, in xy order.
+/// The n-th corner uses end coordinates on axes matching set bits of n, axis 0 being the most significant bit.
+#[derive(Clone, Debug)]
+pub struct CornerIter<N: Scalar, const D: usize> {
+    start: Point<N, D>,
+    end: Point<N, D>,
+    index: usize,
+}
+
+impl<N: Copy + Scalar, const D: usize> CornerIter<N, D> {
+    pub fn new(start: Point<N, D>, end: Point<N, D>) -> CornerIter<N, D> {
+        CornerIter {
+            start,
+            end,
+            index: 0,
+        }
+    }
+
+    /// Computes the n-th corner
+    fn corner(&self, n: usize) -> Point<N, D> {
+        let mut corner = self.start;
+
+        for idx in 0..D {
+            if n & (1 << (D - 1 - idx)) != 0 {
+                unsafe { *corner.get_unchecked_mut(idx) = *self.end.get_unchecked(idx) };
+            }
+        }
+
+        corner
+    }
+}
+
+impl<N: Copy + Scalar, const D: usize> Iterator for CornerIter<N, D> {
+    type Item = Point<N, D>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index < 1 << D {
+            let corner = self.corner(self.index);
+            self.index += 1;
+
+            Some(corner)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (1 << D) - self.index;
+        (len, Some(len))
+    }
+}
+
+impl<N: Copy + Scalar, const D: usize> ExactSizeIterator for CornerIter<N, D> {}
+impl<N: Copy + Scalar, const D: usize> FusedIterator for CornerIter<N, D> {}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use na::point;
+    use super::*;
+
+    #[test]
+    fn test_2d_corners() {
+        let iter = CornerIter::new(point![0, 1], point![2, 3]);
+
+        assert_eq!(iter.len(), 4);
+        assert_eq!(
+            iter.collect::<Vec<_>>(),
+            vec![point![0, 1], point![0, 3], point![2, 1], point![2, 3]]
+        );
+    }
+
+    #[test]
+    fn test_exact_size() {
+        let mut iter = CornerIter::new(point![0, 0, 0], point![1, 1, 1]);
+
+        assert_eq!(iter.len(), 8);
+        iter.next();
+        assert_eq!(iter.len(), 7);
+        assert_eq!(iter.by_ref().count(), 7);
+        assert_eq!(iter.next(), None);
+    }
+}
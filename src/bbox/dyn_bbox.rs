@@ -0,0 +1,163 @@
+use alloc::boxed::Box;
+use na::Scalar;
+
+use crate::{BBox, Holds, IsRangeEmpty};
+use crate::bbox::BBoxElement;
+use crate::bbox::utils::{max_bound, min_bound};
+
+/// Bounding box with a dimension known at runtime, to store boxes of different dimensions together.
+/// Use [`DynBBox::try_into_static`] to get back a [`BBox`] for computations.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DynBBox<N> {
+    ranges: Box<[BBoxElement<N>]>,
+}
+
+impl<N> DynBBox<N> {
+    /// Returns bbox dimension
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    /// use pythagore::bbox::DynBBox;
+    ///
+    /// assert_eq!(DynBBox::from(BBox::from(point![0, 0, 0]..point![5, 5, 5])).dimension(), 3);
+    /// ```
+    #[inline]
+    pub fn dimension(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// Returns bbox ranges, one per axis
+    #[inline]
+    pub fn ranges(&self) -> &[BBoxElement<N>] {
+        &self.ranges
+    }
+
+    /// Tests if bbox holds given coordinates. Returns None if coordinates do not match bbox dimension.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    /// use pythagore::bbox::DynBBox;
+    ///
+    /// let bbox = DynBBox::from(BBox::from(point![0, 0]..point![5, 5]));
+    ///
+    /// assert_eq!(bbox.holds_slice(&[2, 2]), Some(true));
+    /// assert_eq!(bbox.holds_slice(&[2, 8]), Some(false));
+    /// assert_eq!(bbox.holds_slice(&[2, 2, 2]), None);
+    /// ```
+    pub fn holds_slice(&self, coords: &[N]) -> Option<bool>
+    where
+        N: PartialOrd
+    {
+        if coords.len() != self.dimension() {
+            return None;
+        }
+
+        Some(self.ranges.iter().zip(coords).all(|(range, x)| range.holds(x)))
+    }
+
+    /// Computes intersection with other bbox. Returns None if dimensions do not match.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    /// use pythagore::bbox::DynBBox;
+    ///
+    /// let a = DynBBox::from(BBox::from(point![0, 0]..point![5, 5]));
+    /// let b = DynBBox::from(BBox::from(point![2, 2]..));
+    ///
+    /// assert_eq!(a.intersection(&b), Some(DynBBox::from(BBox::from(point![2, 2]..point![5, 5]))));
+    /// ```
+    pub fn intersection(&self, other: &DynBBox<N>) -> Option<DynBBox<N>>
+    where
+        N: Copy + PartialOrd
+    {
+        if other.dimension() != self.dimension() {
+            return None;
+        }
+
+        let ranges = self.ranges.iter().zip(other.ranges.iter())
+            .map(|(l, r)| (max_bound(l.0, r.0), min_bound(l.1, r.1)))
+            .collect();
+
+        Some(DynBBox { ranges })
+    }
+
+    /// Converts back into a static bbox. Returns self if its dimension is not D.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    /// use pythagore::bbox::DynBBox;
+    ///
+    /// let bbox = DynBBox::from(BBox::from(point![0, 0]..point![5, 5]));
+    ///
+    /// assert!(bbox.clone().try_into_static::<3>().is_err());
+    /// assert_eq!(bbox.try_into_static::<2>(), Ok(BBox::from(point![0, 0]..point![5, 5])));
+    /// ```
+    pub fn try_into_static<const D: usize>(self) -> Result<BBox<N, D>, DynBBox<N>>
+    where
+        N: Scalar
+    {
+        match Box::<[BBoxElement<N>; D]>::try_from(self.ranges) {
+            Ok(ranges) => Ok(BBox::from(*ranges)),
+            Err(ranges) => Err(DynBBox { ranges }),
+        }
+    }
+}
+
+// Conversion
+impl<N: Copy + Scalar, const D: usize> From<BBox<N, D>> for DynBBox<N> {
+    fn from(value: BBox<N, D>) -> Self {
+        DynBBox { ranges: Box::new(value.ranges) }
+    }
+}
+
+// Utils
+impl<N: PartialOrd> IsRangeEmpty for DynBBox<N> {
+    fn is_range_empty(&self) -> bool {
+        self.ranges.iter().any(|range| range.is_range_empty())
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use core::ops::Bound::{Excluded, Included, Unbounded};
+    use na::point;
+    use crate::Intersection;
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let bbox = BBox::from([(Included(0), Excluded(5)), (Excluded(1), Unbounded)]);
+
+        assert_eq!(DynBBox::from(bbox).try_into_static::<2>(), Ok(bbox));
+    }
+
+    #[test]
+    fn test_dimension_mismatch() {
+        let flat = DynBBox::from(BBox::from(point![0, 0]..point![5, 5]));
+        let cube = DynBBox::from(BBox::from(point![0, 0, 0]..point![5, 5, 5]));
+
+        assert_eq!(flat.clone().try_into_static::<3>(), Err(flat.clone()));
+        assert_eq!(flat.intersection(&cube), None);
+        assert_eq!(cube.holds_slice(&[1, 1]), None);
+    }
+
+    #[test]
+    fn test_intersection() {
+        let a = BBox::from([(Included(0), Excluded(5)), (Excluded(1), Unbounded)]);
+        let b = BBox::from([(Excluded(0), Included(3)), (Included(4), Excluded(2))]);
+
+        let res = DynBBox::from(a).intersection(&DynBBox::from(b)).unwrap();
+
+        assert!(res.is_range_empty());
+        assert_eq!(res.try_into_static::<2>(), Ok(a.intersection(&b)));
+    }
+}
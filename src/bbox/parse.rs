@@ -0,0 +1,234 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+use core::ops::Bound::{self, Excluded, Included, Unbounded};
+use core::str::FromStr;
+use na::Scalar;
+
+use crate::BBox;
+use crate::bbox::BBoxElement;
+
+/// Error returned when parsing a bbox from a string fails
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseBBoxError {
+    /// String does not hold the expected number of axes
+    AxisCount { expected: usize, found: usize },
+
+    /// Axis is not made of two bounds, separated by a comma and enclosed in brackets
+    MalformedAxis { axis: usize, token: String },
+
+    /// A bound value could not be parsed
+    InvalidScalar { axis: usize, token: String },
+}
+
+impl Display for ParseBBoxError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseBBoxError::AxisCount { expected, found } => write!(f, "expected {expected} axes, found {found}"),
+            ParseBBoxError::MalformedAxis { axis, token } => write!(f, "malformed axis {axis}: {token:?}"),
+            ParseBBoxError::InvalidScalar { axis, token } => write!(f, "invalid scalar on axis {axis}: {token:?}"),
+        }
+    }
+}
+
+impl core::error::Error for ParseBBoxError {}
+
+/// Formats bbox with one factor per axis, separated by `x`. Each factor uses `[` or `]` for included
+/// bounds, `(` or `)` for excluded ones and `..` for unbounded sides. Fully unbounded axes are written `(..)`.
+///
+/// # Example
+/// ```
+/// use std::ops::Bound::{Excluded, Included, Unbounded};
+/// use pythagore::BBox;
+///
+/// let bbox = BBox::from([(Included(0), Excluded(5)), (Excluded(-3), Included(3)), (Unbounded, Unbounded)]);
+///
+/// assert_eq!(bbox.to_string(), "[0,5)x(-3,3]x(..)");
+/// ```
+impl<N: Display + Scalar, const D: usize> Display for BBox<N, D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (idx, range) in self.ranges.iter().enumerate() {
+            if idx > 0 {
+                f.write_str("x")?;
+            }
+
+            match range {
+                (Unbounded, Unbounded) => f.write_str("(..)")?,
+                (start, end) => {
+                    match start {
+                        Included(x) => write!(f, "[{x}")?,
+                        Excluded(x) => write!(f, "({x}")?,
+                        Unbounded => f.write_str("(..")?,
+                    }
+
+                    f.write_str(",")?;
+
+                    match end {
+                        Included(x) => write!(f, "{x}]")?,
+                        Excluded(x) => write!(f, "{x})")?,
+                        Unbounded => f.write_str("..)")?,
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses bbox from the format written by its Display implementation. Whitespaces are allowed
+/// around tokens, and axes may also be separated by `×`.
+///
+/// # Example
+/// ```
+/// use std::ops::Bound::{Excluded, Included, Unbounded};
+/// use pythagore::BBox;
+///
+/// assert_eq!(
+///     "[0, 5) × (-3, ..)".parse::<BBox<i32, 2>>(),
+///     Ok(BBox::from([(Included(0), Excluded(5)), (Excluded(-3), Unbounded)]))
+/// );
+/// ```
+impl<N: FromStr + Scalar, const D: usize> FromStr for BBox<N, D> {
+    type Err = ParseBBoxError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut ranges = Vec::with_capacity(D);
+        let mut rest = s.trim();
+
+        loop {
+            let axis = ranges.len();
+            let close = rest.find([']', ')'])
+                .ok_or_else(|| ParseBBoxError::MalformedAxis { axis, token: rest.to_string() })?;
+
+            ranges.push(parse_axis(axis, &rest[..=close])?);
+            rest = rest[close + 1..].trim_start();
+
+            if rest.is_empty() {
+                break;
+            }
+
+            rest = rest.strip_prefix(['x', '×'])
+                .ok_or_else(|| ParseBBoxError::MalformedAxis { axis: axis + 1, token: rest.to_string() })?
+                .trim_start();
+        }
+
+        let found = ranges.len();
+        let ranges: [BBoxElement<N>; D] = ranges.try_into()
+            .map_err(|_| ParseBBoxError::AxisCount { expected: D, found })?;
+
+        Ok(BBox::from(ranges))
+    }
+}
+
+/// Parses one axis factor, brackets included
+fn parse_axis<N: FromStr>(axis: usize, token: &str) -> Result<BBoxElement<N>, ParseBBoxError> {
+    let malformed = || ParseBBoxError::MalformedAxis { axis, token: token.to_string() };
+
+    let mut chars = token.chars();
+    let open = chars.next().ok_or_else(malformed)?;
+    let close = chars.next_back().ok_or_else(malformed)?;
+    let inner = chars.as_str().trim();
+
+    if !matches!(open, '[' | '(') {
+        return Err(malformed());
+    }
+
+    if inner == ".." {
+        return if open == '(' && close == ')' { Ok((Unbounded, Unbounded)) } else { Err(malformed()) };
+    }
+
+    let (start, end) = inner.split_once(',').ok_or_else(malformed)?;
+
+    let start = parse_bound(axis, start.trim(), open == '[').ok_or_else(malformed)??;
+    let end = parse_bound(axis, end.trim(), close == ']').ok_or_else(malformed)??;
+
+    Ok((start, end))
+}
+
+/// Parses one bound value, returns None if an included bound is unbounded
+fn parse_bound<N: FromStr>(axis: usize, token: &str, included: bool) -> Option<Result<Bound<N>, ParseBBoxError>> {
+    if token == ".." {
+        return if included { None } else { Some(Ok(Unbounded)) };
+    }
+
+    let value = token.parse()
+        .map_err(|_| ParseBBoxError::InvalidScalar { axis, token: token.to_string() });
+
+    Some(value.map(|x| if included { Included(x) } else { Excluded(x) }))
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+    use proptest::prelude::*;
+    use crate::bbox::arbitrary::BBoxParams;
+    use super::*;
+
+    const KINDS: [fn(i32) -> Bound<i32>; 3] = [Included, Excluded, |_| Unbounded];
+
+    #[test]
+    fn test_round_trip_all_kinds() {
+        for start in KINDS {
+            for end in KINDS {
+                let bbox = BBox::from([(start(-4), end(7)), (Included(0), Excluded(5)), (end(2), start(1))]);
+                let text = bbox.to_string();
+
+                assert_eq!(text.parse::<BBox<i32, 3>>(), Ok(bbox), "{text}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_round_trip_floats() {
+        let bbox = BBox::from([(Included(-0.5), Excluded(2.25)), (Unbounded, Included(1e-3))]);
+
+        assert_eq!(bbox.to_string().parse::<BBox<f64, 2>>(), Ok(bbox));
+    }
+
+    #[test]
+    fn test_parse_whitespaces() {
+        assert_eq!(
+            "  [ 0 , 5 )x( .. )  ×(..,3] ".parse::<BBox<i32, 3>>(),
+            Ok(BBox::from([(Included(0), Excluded(5)), (Unbounded, Unbounded), (Unbounded, Included(3))]))
+        );
+    }
+
+    #[test]
+    fn test_axis_count_error() {
+        let err = "[0,5)x[0,5)".parse::<BBox<i32, 3>>().unwrap_err();
+
+        assert_eq!(err, ParseBBoxError::AxisCount { expected: 3, found: 2 });
+        assert_eq!(format!("{err}"), "expected 3 axes, found 2");
+    }
+
+    #[test]
+    fn test_malformed_axis_error() {
+        let err = "[0,5)x{1,2}".parse::<BBox<i32, 2>>().unwrap_err();
+
+        assert_eq!(err, ParseBBoxError::MalformedAxis { axis: 1, token: String::from("{1,2}") });
+        assert_eq!(format!("{err}"), "malformed axis 1: \"{1,2}\"");
+
+        assert!(matches!("[0 5)".parse::<BBox<i32, 1>>(), Err(ParseBBoxError::MalformedAxis { axis: 0, .. })));
+        assert!(matches!("[..,5)".parse::<BBox<i32, 1>>(), Err(ParseBBoxError::MalformedAxis { axis: 0, .. })));
+        assert!(matches!("[..]".parse::<BBox<i32, 1>>(), Err(ParseBBoxError::MalformedAxis { axis: 0, .. })));
+        assert!(matches!("[0,5) [0,5)".parse::<BBox<i32, 2>>(), Err(ParseBBoxError::MalformedAxis { axis: 1, .. })));
+        assert!(matches!("".parse::<BBox<i32, 1>>(), Err(ParseBBoxError::MalformedAxis { axis: 0, .. })));
+    }
+
+    #[test]
+    fn test_invalid_scalar_error() {
+        let err = "[0,5)x[a,5)".parse::<BBox<i32, 2>>().unwrap_err();
+
+        assert_eq!(err, ParseBBoxError::InvalidScalar { axis: 1, token: String::from("a") });
+        assert_eq!(format!("{err}"), "invalid scalar on axis 1: \"a\"");
+    }
+
+    proptest! {
+        #[test]
+        fn display_round_trip(bbox in any_with::<BBox<i32, 3>>(BBoxParams { allow_unbounded: true, allow_empty: true })) {
+            prop_assert_eq!(bbox.to_string().parse::<BBox<i32, 3>>(), Ok(bbox));
+        }
+    }
+}
@@ -0,0 +1,323 @@
+use alloc::vec::Vec;
+use core::ops::Bound::Included;
+use na::{ClosedAdd, ClosedSub, Point, Scalar, SVector};
+use num_traits::{CheckedAdd, CheckedSub, NumCast, One, Zero};
+
+use crate::{BBox, Walkable};
+use crate::bbox::WrapScalar;
+use crate::bbox_walker::BBoxWalker;
+
+/// Box inside a periodic domain, which may wrap around the domain seam on any axis.
+/// Each axis covers values from start to start + extent (included), modulo the domain period.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PeriodicBBox<N: Scalar, const D: usize> {
+    domain: BBox<N, D>,
+    start: Point<N, D>,
+    extent: SVector<N, D>,
+    periods: [(N, N); D],
+}
+
+impl<N: ClosedAdd + ClosedSub + WrapScalar + Zero, const D: usize> PeriodicBBox<N, D> {
+    /// Builds a periodic box covering from start to start + extent (included) on each axis, wrapped
+    /// inside domain. Returns None if domain is unbounded or empty, or if an extent is negative.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::{point, vector};
+    /// use pythagore::BBox;
+    /// use pythagore::bbox::PeriodicBBox;
+    ///
+    /// let domain = BBox::from(point![0]..point![256]);
+    /// let bbox = PeriodicBBox::new(domain, point![-6], vector![15]).unwrap();
+    ///
+    /// assert_eq!(bbox.start(), &point![250]);
+    /// ```
+    pub fn new(domain: BBox<N, D>, start: Point<N, D>, extent: SVector<N, D>) -> Option<PeriodicBBox<N, D>> {
+        let mut start = start;
+        let mut periods = [(N::zero(), N::zero()); D];
+
+        for (idx, period) in periods.iter_mut().enumerate() {
+            let (first, len) = N::period(&domain[idx])?;
+            *period = (first, len);
+
+            if unsafe { *extent.get_unchecked(idx) } < N::zero() {
+                return None;
+            }
+
+            let x = unsafe { start.get_unchecked_mut(idx) };
+            *x = N::wrap(*x, first, len);
+        }
+
+        Some(PeriodicBBox { domain, start, extent, periods })
+    }
+
+    /// Builds a periodic box from its center and half extents, both ends included, wrapped inside
+    /// domain. Returns None if domain is unbounded or empty, or if a half extent is negative.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::{point, vector};
+    /// use pythagore::BBox;
+    /// use pythagore::bbox::PeriodicBBox;
+    ///
+    /// let domain = BBox::from(point![0, 0]..point![256, 256]);
+    /// let bbox = PeriodicBBox::from_center_half_extents(domain, &point![2, 128], &vector![8, 8]).unwrap();
+    ///
+    /// assert!(bbox.holds(&point![250, 128]));
+    /// assert!(bbox.holds(&point![10, 128]));
+    /// assert!(!bbox.holds(&point![11, 128]));
+    /// ```
+    pub fn from_center_half_extents(domain: BBox<N, D>, center: &Point<N, D>, half: &SVector<N, D>) -> Option<PeriodicBBox<N, D>> {
+        let mut start = *center;
+
+        for (idx, x) in start.iter_mut().enumerate() {
+            let (first, len) = N::period(&domain[idx])?;
+            let c = N::wrap(*x, first, len);
+            let h = N::wrap(unsafe { *half.get_unchecked(idx) }, N::zero(), len);
+
+            // Moves back by half modulo the period, so unsigned coordinates never go below the domain
+            *x = if c - first >= h { c - h } else { c + (len - h) };
+        }
+
+        PeriodicBBox::new(domain, start, half + half)
+    }
+
+    /// Returns the domain in which the box wraps
+    #[inline]
+    pub fn domain(&self) -> &BBox<N, D> {
+        &self.domain
+    }
+
+    /// Returns the start point of the box, inside domain
+    #[inline]
+    pub fn start(&self) -> &Point<N, D> {
+        &self.start
+    }
+
+    /// Returns the extent of the box on each axis
+    #[inline]
+    pub fn extent(&self) -> &SVector<N, D> {
+        &self.extent
+    }
+
+    /// Tests if given point is held by the box. Points outside domain are wrapped inside it first.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::{point, vector};
+    /// use pythagore::BBox;
+    /// use pythagore::bbox::PeriodicBBox;
+    ///
+    /// let domain = BBox::from(point![0]..point![256]);
+    /// let bbox = PeriodicBBox::new(domain, point![250], vector![15]).unwrap();
+    ///
+    /// assert!(bbox.holds(&point![5]));
+    /// assert!(bbox.holds(&point![261]));
+    /// assert!(!bbox.holds(&point![100]));
+    /// ```
+    pub fn holds(&self, point: &Point<N, D>) -> bool {
+        (0..D).all(|idx| {
+            let (first, len) = unsafe { *self.periods.get_unchecked(idx) };
+            let start = unsafe { *self.start.get_unchecked(idx) };
+            let x = N::wrap(unsafe { *point.get_unchecked(idx) }, first, len);
+
+            // Both x and start are inside domain, so offset never leaves the scalar range
+            let offset = if x >= start { x - start } else { len - (start - x) };
+
+            offset <= unsafe { *self.extent.get_unchecked(idx) }
+        })
+    }
+
+    /// Decomposes the box into at most 2^D ordinary boxes, holding the same points inside domain.
+    /// Boxes are ordered as the box wraps: pieces starting at the box start come first.
+    ///
+    /// # Example
+    /// ```
+    /// use std::ops::Bound::{Excluded, Included};
+    /// use nalgebra::{point, vector};
+    /// use pythagore::BBox;
+    /// use pythagore::bbox::PeriodicBBox;
+    ///
+    /// let domain = BBox::from(point![0]..point![256]);
+    /// let bbox = PeriodicBBox::new(domain, point![250], vector![15]).unwrap();
+    ///
+    /// assert_eq!(bbox.to_bboxes(), vec![
+    ///     BBox::from([(Included(250), Excluded(256))]),
+    ///     BBox::from([(Included(0), Included(9))]),
+    /// ]);
+    /// ```
+    pub fn to_bboxes(&self) -> Vec<BBox<N, D>> {
+        let mut pieces = [[None; 2]; D];
+
+        for (idx, axis) in pieces.iter_mut().enumerate() {
+            let range = unsafe { self.domain.ranges.get_unchecked(idx) };
+            let (first, len) = unsafe { *self.periods.get_unchecked(idx) };
+            let start = unsafe { *self.start.get_unchecked(idx) };
+            let extent = unsafe { *self.extent.get_unchecked(idx) };
+
+            if extent >= len {
+                *axis = [Some(*range), None];
+            } else if extent < len - (start - first) {
+                *axis = [Some((Included(start), Included(start + extent))), None];
+            } else {
+                *axis = [
+                    Some((Included(start), range.1)),
+                    Some((Included(first), Included(first + (extent - (len - (start - first)))))),
+                ];
+            }
+        }
+
+        let mut bboxes = Vec::new();
+
+        'masks: for mask in 0..(1usize << D) {
+            let mut bbox = self.domain;
+
+            for (idx, axis) in pieces.iter().enumerate() {
+                match axis[(mask >> (D - 1 - idx)) & 1] {
+                    Some(range) => bbox.ranges[idx] = range,
+                    None => continue 'masks,
+                }
+            }
+
+            bboxes.push(bbox);
+        }
+
+        bboxes
+    }
+
+    /// Returns an iterator over all lattice points of the box, walking each piece of its decomposition
+    /// in order (see [`PeriodicBBox::to_bboxes`]).
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::{point, vector};
+    /// use pythagore::BBox;
+    /// use pythagore::bbox::PeriodicBBox;
+    ///
+    /// let domain = BBox::from(point![0]..point![8]);
+    /// let bbox = PeriodicBBox::new(domain, point![6], vector![3]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     bbox.walker().collect::<Vec<_>>(),
+    ///     vec![point![6], point![7], point![0], point![1]],
+    /// );
+    /// ```
+    pub fn walker(&self) -> impl Iterator<Item = Point<N, D>>
+    where
+        N: CheckedAdd + CheckedSub + NumCast + One + Ord
+    {
+        self.to_bboxes().into_iter()
+            .filter_map(|bbox| bbox.walk().ok())
+            .flat_map(BBoxWalker::into_iter)
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use na::{point, vector};
+    use crate::Holds;
+    use super::*;
+
+    #[test]
+    fn test_invalid_domain() {
+        assert_eq!(PeriodicBBox::new(BBox::from(point![0]..), point![0], vector![1]), None);
+        assert_eq!(PeriodicBBox::new(BBox::from(point![0]..point![0]), point![0], vector![1]), None);
+        assert_eq!(PeriodicBBox::new(BBox::from(point![0]..point![8]), point![0], vector![-1]), None);
+    }
+
+    #[test]
+    fn test_seam_decomposition() {
+        let domain = BBox::from(point![0]..point![256]);
+        let bbox = PeriodicBBox::from_center_half_extents(domain, &point![2], &vector![8]).unwrap();
+        let pieces = bbox.to_bboxes();
+
+        assert_eq!(pieces.len(), 2);
+        assert_eq!(pieces.iter().map(|piece| piece.walk().unwrap().iter().count()).sum::<usize>(), 17);
+        assert_eq!(bbox.walker().count(), 17);
+    }
+
+    #[test]
+    fn test_holds_matches_decomposition() {
+        let domain = BBox::from(point![-4, 0]..point![12, 10]);
+        let bbox = PeriodicBBox::new(domain, point![9, 7], vector![5, 4]).unwrap();
+        let pieces = bbox.to_bboxes();
+
+        assert_eq!(pieces.len(), 4);
+
+        for pt in domain.walk().unwrap().iter() {
+            let held = pieces.iter().filter(|piece| piece.holds(&pt)).count();
+
+            assert!(held <= 1, "{pt} is held by many pieces");
+            assert_eq!(bbox.holds(&pt), held == 1, "mismatch on {pt}");
+        }
+    }
+
+    #[test]
+    fn test_non_wrapping_decomposition() {
+        let domain = BBox::from(point![0, 0]..point![10, 10]);
+        let bbox = PeriodicBBox::new(domain, point![2, 3], vector![4, 5]).unwrap();
+
+        assert_eq!(bbox.to_bboxes(), vec![BBox::from(point![2, 3]..=point![6, 8])]);
+    }
+
+    #[test]
+    fn test_full_axis() {
+        let domain = BBox::from(point![0, 0]..point![10, 10]);
+        let bbox = PeriodicBBox::new(domain, point![2, 3], vector![20, 10]).unwrap();
+
+        assert_eq!(bbox.to_bboxes(), vec![domain]);
+        assert_eq!(bbox.walker().count(), 100);
+    }
+
+    #[test]
+    fn test_unsigned_holds() {
+        let domain = BBox::from(point![0u32]..point![256u32]);
+        let bbox = PeriodicBBox::new(domain, point![250], vector![15]).unwrap();
+
+        assert!(bbox.holds(&point![5]));
+        assert!(bbox.holds(&point![252]));
+        assert!(bbox.holds(&point![261]));
+        assert!(!bbox.holds(&point![10]));
+        assert!(!bbox.holds(&point![100]));
+
+        let domain = BBox::from(point![0u8]..point![200u8]);
+        let bbox = PeriodicBBox::new(domain, point![150], vector![100]).unwrap();
+
+        assert!(bbox.holds(&point![199]));
+        assert!(bbox.holds(&point![50]));
+        assert!(!bbox.holds(&point![51]));
+        assert!(bbox.holds(&point![245]));
+        assert!(!bbox.holds(&point![255]));
+        assert_eq!(bbox.walker().count(), 101);
+    }
+
+    #[test]
+    fn test_unsigned_from_center() {
+        let domain = BBox::from(point![0u32, 0]..point![256, 256]);
+        let bbox = PeriodicBBox::from_center_half_extents(domain, &point![2, 128], &vector![8, 8]).unwrap();
+
+        assert_eq!(bbox.start(), &point![250, 120]);
+        assert!(bbox.holds(&point![250, 128]));
+        assert!(bbox.holds(&point![10, 136]));
+        assert!(!bbox.holds(&point![11, 128]));
+
+        let domain = BBox::from(point![10u8]..point![20]);
+        let bbox = PeriodicBBox::from_center_half_extents(domain, &point![11], &vector![13]).unwrap();
+
+        assert_eq!(bbox.start(), &point![18]);
+        assert_eq!(bbox.extent(), &vector![26]);
+        assert!(bbox.holds(&point![15]));
+    }
+
+    #[test]
+    fn test_float_holds() {
+        let domain = BBox::from(point![0.0]..point![1.0]);
+        let bbox = PeriodicBBox::new(domain, point![0.75], vector![0.5]).unwrap();
+
+        assert!(bbox.holds(&point![0.9]));
+        assert!(bbox.holds(&point![0.25]));
+        assert!(bbox.holds(&point![1.1]));
+        assert!(!bbox.holds(&point![0.5]));
+    }
+}
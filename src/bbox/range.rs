@@ -1,11 +1,11 @@
-use std::cmp::max;
-use std::ops::Bound::{Excluded, Included, Unbounded};
-use std::ops::{Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
-use na::{ClosedSub, Point, Scalar, SVector};
-use num_traits::One;
+use core::cmp::max;
+use core::ops::Bound::{Excluded, Included, Unbounded};
+use core::ops::{Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+use na::{Point, Scalar};
+use num_traits::{CheckedSub, One};
 
 use crate::{BBox, Intersection, PointBounds, Walkable};
-use crate::bbox::utils::{max_bound, max_point, min_bound, min_point};
+use crate::bbox::utils::{max_bound, max_point, min_bound, min_point, prev_lattice_point};
 use crate::traits::DimBounds;
 
 /// Builds a bounding box from a range of points
@@ -58,7 +58,8 @@ impl<N: Copy + Scalar, const D: usize> PointBounds<N, D> for Range<Point<N, D>>
     }
 }
 
-impl<N: ClosedSub + Copy + One + Scalar, const D: usize> Walkable<N, D> for Range<Point<N, D>> {
+/// Last point is None if the end is at the scalar minimum on any axis
+impl<N: CheckedSub + Copy + One + Scalar, const D: usize> Walkable<N, D> for Range<Point<N, D>> {
     #[inline]
     fn first_point(&self) -> Option<Point<N, D>> {
         Some(self.start)
@@ -66,7 +67,7 @@ impl<N: ClosedSub + Copy + One + Scalar, const D: usize> Walkable<N, D> for Rang
 
     #[inline]
     fn last_point(&self) -> Option<Point<N, D>> {
-        Some(self.end - SVector::repeat(N::one()))
+        prev_lattice_point(&self.end)
     }
 }
 
@@ -205,8 +206,9 @@ mod tests {
 
         #[test]
         fn test_get_bounds() {
-            assert_eq!((point![1, 2]..point![3, 4]).get_bounds(0), 1..3);
-            assert_eq!((point![1, 2]..point![3, 4]).get_bounds(1), 2..4);
+            assert_eq!((point![1, 2]..point![3, 4]).get_bounds(0), Some(1..3));
+            assert_eq!((point![1, 2]..point![3, 4]).get_bounds(1), Some(2..4));
+            assert_eq!((point![1, 2]..point![3, 4]).get_bounds(2), None);
         }
     }
 
@@ -249,6 +251,15 @@ mod tests {
                 (point![0, 0]..point![5, 5]).last_point(),
                 Some(point![4, 4])
             );
+            assert_eq!((point![0u8, 5]..point![0, 5]).last_point(), None);
+        }
+
+        #[test]
+        fn test_iter_points() {
+            assert_eq!(
+                (point![0, 0]..point![2, 2]).iter_points().unwrap().collect::<Vec<_>>(),
+                vec![point![0, 0], point![0, 1], point![1, 0], point![1, 1]]
+            );
         }
     }
-}
\ No newline at end of file
+}
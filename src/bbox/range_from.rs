@@ -1,5 +1,5 @@
-use std::ops::Bound::{Included, Unbounded};
-use std::ops::{Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+use core::ops::Bound::{Included, Unbounded};
+use core::ops::{Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
 use na::{Point, Scalar};
 
 use crate::{BBox, Intersection, PointBounds};
@@ -157,8 +157,9 @@ mod tests {
 
         #[test]
         fn test_get_bounds() {
-            assert_eq!((point![1, 2]..).get_bounds(0), 1..);
-            assert_eq!((point![1, 2]..).get_bounds(1), 2..);
+            assert_eq!((point![1, 2]..).get_bounds(0), Some(1..));
+            assert_eq!((point![1, 2]..).get_bounds(1), Some(2..));
+            assert_eq!((point![1, 2]..).get_bounds(2), None);
         }
     }
 
@@ -1,5 +1,5 @@
-use std::ops::Bound::Unbounded;
-use std::ops::{Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+use core::ops::Bound::Unbounded;
+use core::ops::{Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
 use na::{Point, Scalar};
 
 use crate::{BBox, Intersection, PointBounds};
@@ -30,11 +30,6 @@ impl<N: Copy + Scalar, const D: usize> From<RangeFull> for BBox<N, D> {
 impl<N: Scalar, const D: usize> DimBounds<N, D> for RangeFull {
     type Output = RangeFull;
 
-    #[inline]
-    fn get_bounds(&self, _idx: usize) -> Self::Output {
-        ..
-    }
-
     #[inline]
     unsafe fn get_bounds_unchecked(&self, _idx: usize) -> Self::Output {
         ..
@@ -146,8 +141,9 @@ mod tests {
 
         #[test]
         fn test_get_bounds() {
-            assert_eq!(DimBounds::<i32, 2>::get_bounds(&(..), 0), ..);
-            assert_eq!(DimBounds::<i32, 2>::get_bounds(&(..), 1), ..);
+            assert_eq!(DimBounds::<i32, 2>::get_bounds(&(..), 0), Some(..));
+            assert_eq!(DimBounds::<i32, 2>::get_bounds(&(..), 1), Some(..));
+            assert_eq!(DimBounds::<i32, 2>::get_bounds(&(..), 2), None);
         }
     }
 
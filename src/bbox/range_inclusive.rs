@@ -1,6 +1,6 @@
-use std::cmp::max;
-use std::ops::Bound::{Excluded, Included, Unbounded};
-use std::ops::{Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+use core::cmp::max;
+use core::ops::Bound::{Excluded, Included, Unbounded};
+use core::ops::{Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
 use na::{Point, Scalar};
 
 use crate::{BBox, Intersection, PointBounds, Walkable};
@@ -199,8 +199,9 @@ mod tests {
 
         #[test]
         fn test_get_bounds() {
-            assert_eq!((point![1, 2]..=point![3, 4]).get_bounds(0), 1..=3);
-            assert_eq!((point![1, 2]..=point![3, 4]).get_bounds(1), 2..=4);
+            assert_eq!((point![1, 2]..=point![3, 4]).get_bounds(0), Some(1..=3));
+            assert_eq!((point![1, 2]..=point![3, 4]).get_bounds(1), Some(2..=4));
+            assert_eq!((point![1, 2]..=point![3, 4]).get_bounds(2), None);
         }
     }
 
@@ -1,5 +1,5 @@
-use std::ops::Bound::{Excluded, Included, Unbounded};
-use std::ops::{Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+use core::ops::Bound::{Excluded, Included, Unbounded};
+use core::ops::{Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
 use na::{Point, Scalar};
 
 use crate::{BBox, Intersection, PointBounds};
@@ -181,8 +181,9 @@ mod tests {
 
         #[test]
         fn test_get_bounds() {
-            assert_eq!((..point![3, 4]).get_bounds(0), ..3);
-            assert_eq!((..point![3, 4]).get_bounds(1), ..4);
+            assert_eq!((..point![3, 4]).get_bounds(0), Some(..3));
+            assert_eq!((..point![3, 4]).get_bounds(1), Some(..4));
+            assert_eq!((..point![3, 4]).get_bounds(2), None);
         }
     }
 
@@ -0,0 +1,192 @@
+use core::fmt::Debug;
+use core::ops::Bound;
+use core::ops::Bound::{Excluded, Included, Unbounded};
+use na::{Point, Scalar};
+use rand::distributions::{Distribution, Uniform};
+use rand::distributions::uniform::SampleUniform;
+use rand::Rng;
+
+use crate::BBox;
+
+/// Scalar types that can be uniformly sampled inside a range
+pub trait SampleScalar: Copy + PartialOrd + SampleUniform<Sampler: Copy + Debug> + Scalar {
+    /// Returns uniform distribution over values held by given range, None if range is unbounded or empty
+    fn uniform(range: &(Bound<Self>, Bound<Self>)) -> Option<Uniform<Self>>;
+}
+
+macro_rules! sample_scalar_int_impl {
+    ($($t:ty),*) => {$(
+        impl SampleScalar for $t {
+            fn uniform(range: &(Bound<$t>, Bound<$t>)) -> Option<Uniform<$t>> {
+                let low = match range.0 {
+                    Included(x) => x,
+                    Excluded(x) => x.checked_add(1)?,
+                    Unbounded => return None,
+                };
+
+                match range.1 {
+                    Included(high) if low <= high => Some(Uniform::new_inclusive(low, high)),
+                    Excluded(high) if low < high => Some(Uniform::new(low, high)),
+                    _ => None,
+                }
+            }
+        }
+    )*};
+}
+
+macro_rules! sample_scalar_float_impl {
+    ($($t:ty),*) => {$(
+        impl SampleScalar for $t {
+            fn uniform(range: &(Bound<$t>, Bound<$t>)) -> Option<Uniform<$t>> {
+                let low = match range.0 {
+                    Included(x) => x,
+                    Excluded(x) => x.next_up(),
+                    Unbounded => return None,
+                };
+
+                match range.1 {
+                    Included(high) if low <= high && (high - low).is_finite() => Some(Uniform::new_inclusive(low, high)),
+                    Excluded(high) if low < high && (high - low).is_finite() => Some(Uniform::new(low, high)),
+                    _ => None,
+                }
+            }
+        }
+    )*};
+}
+
+sample_scalar_int_impl!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+sample_scalar_float_impl!(f32, f64);
+
+/// Uniform distribution of points inside a bounded and non-empty bbox
+///
+/// # Example
+/// ```
+/// use nalgebra::point;
+/// use pythagore::{BBox, Holds, UniformBBox};
+/// use rand::rngs::StdRng;
+/// use rand::{Rng, SeedableRng};
+///
+/// let bbox = BBox::from(point![0, 0]..point![5, 5]);
+/// let distribution = UniformBBox::new(&bbox).unwrap();
+///
+/// assert!(StdRng::seed_from_u64(42).sample_iter(&distribution).take(10).all(|pt| bbox.holds(&pt)));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct UniformBBox<N: SampleScalar, const D: usize> {
+    axes: [Uniform<N>; D],
+}
+
+impl<N: SampleScalar, const D: usize> UniformBBox<N, D> {
+    /// Builds distribution over given bbox, None if bbox is unbounded or empty
+    pub fn new(bbox: &BBox<N, D>) -> Option<UniformBBox<N, D>> {
+        let axes: [Option<Uniform<N>>; D] = core::array::from_fn(|idx| N::uniform(&bbox[idx]));
+
+        if axes.iter().any(Option::is_none) {
+            return None;
+        }
+
+        Some(UniformBBox { axes: axes.map(Option::unwrap) })
+    }
+}
+
+impl<N: SampleScalar, const D: usize> Distribution<Point<N, D>> for UniformBBox<N, D> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Point<N, D> {
+        Point::from(core::array::from_fn(|idx| self.axes[idx].sample(rng)))
+    }
+}
+
+impl<N: SampleScalar, const D: usize> BBox<N, D> {
+    /// Samples a random point inside bbox, None if bbox is unbounded or empty.
+    /// Integer scalars are sampled over the held lattice points, float scalars over the held interval.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::{BBox, Holds};
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    ///
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// let bbox = BBox::from(point![0.0, 0.0]..point![5.0, 5.0]);
+    ///
+    /// assert!(bbox.holds(&bbox.sample(&mut rng).unwrap()));
+    /// assert!(BBox::from(point![0.0, 0.0]..).sample(&mut rng).is_none());
+    /// ```
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<Point<N, D>> {
+        UniformBBox::new(self).map(|distribution| distribution.sample(rng))
+    }
+
+    /// Returns an iterator over n random points inside bbox. Yields nothing if bbox is unbounded or empty.
+    pub fn sample_iter<'a, R: Rng + ?Sized>(&self, rng: &'a mut R, n: usize) -> impl Iterator<Item = Point<N, D>> + 'a
+    where
+        N: 'a
+    {
+        let distribution = UniformBBox::new(self);
+
+        (0..n).map_while(move |_| distribution.map(|distribution| distribution.sample(rng)))
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use na::point;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use crate::Holds;
+    use super::*;
+
+    #[test]
+    fn test_sampled_points_are_held() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let bbox = BBox::from([(Excluded(-3), Included(3)), (Included(0), Excluded(2)), (Included(5), Included(5))]);
+
+        assert!(bbox.sample_iter(&mut rng, 100).all(|pt| bbox.holds(&pt)));
+        assert_eq!(bbox.sample_iter(&mut rng, 100).count(), 100);
+    }
+
+    #[test]
+    fn test_sampled_float_points_are_held() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let bbox = BBox::from([(Excluded(-1.0), Excluded(1.0)), (Included(0.5), Included(0.5))]);
+
+        assert!(bbox.sample_iter(&mut rng, 100).all(|pt| bbox.holds(&pt)));
+    }
+
+    #[test]
+    fn test_excluded_end_never_sampled() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let bbox = BBox::from(point![0]..point![1]);
+
+        assert!(bbox.sample_iter(&mut rng, 100).all(|pt| pt == point![0]));
+
+        let bbox = BBox::from([(Excluded(0), Included(1))]);
+
+        assert!(bbox.sample_iter(&mut rng, 100).all(|pt| pt == point![1]));
+    }
+
+    #[test]
+    fn test_distribution() {
+        let bbox = BBox::from(point![0, 0]..point![5, 5]);
+        let distribution = UniformBBox::new(&bbox).unwrap();
+
+        assert!(StdRng::seed_from_u64(42).sample_iter(&distribution).take(100).all(|pt| bbox.holds(&pt)));
+    }
+
+    #[test]
+    fn test_empty_bbox() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        assert_eq!(BBox::from(point![0, 0]..point![0, 5]).sample(&mut rng), None);
+        assert_eq!(BBox::from([(Excluded(0), Excluded(1))]).sample(&mut rng), None);
+        assert_eq!(BBox::from(point![0.0, 0.0]..point![0.0, 5.0]).sample_iter(&mut rng, 10).count(), 0);
+    }
+
+    #[test]
+    fn test_unbounded_bbox() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        assert_eq!(BBox::from(point![0, 0]..).sample(&mut rng), None);
+        assert_eq!(BBox::from([(Included(0.0), Included(f64::INFINITY))]).sample(&mut rng), None);
+    }
+}
@@ -0,0 +1,242 @@
+use core::ops::Bound::{self, Excluded, Included, Unbounded};
+use na::{Scalar, SVector};
+use num_traits::float::FloatCore;
+
+use crate::BBox;
+
+impl<N: FloatCore + Scalar, const D: usize> BBox<N, D> {
+    /// Rounds each start down and each end up to a multiple of the cell size of its axis, keeping
+    /// bound kinds. The result holds the bbox. Cell sizes must be positive.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::{point, vector};
+    /// use pythagore::BBox;
+    ///
+    /// assert_eq!(
+    ///     BBox::from(point![0.3, -1.7]..point![4.2, 0.1]).snap_outward(&vector![1.0, 1.0]),
+    ///     BBox::from(point![0.0, -2.0]..point![5.0, 1.0])
+    /// );
+    /// ```
+    pub fn snap_outward(&self, cell: &SVector<N, D>) -> BBox<N, D> {
+        let mut result = *self;
+
+        for (idx, range) in result.iter_mut().enumerate() {
+            let size = unsafe { *cell.get_unchecked(idx) };
+
+            range.0 = snap_bound(range.0, |x| FloatCore::floor(x / size) * size);
+            range.1 = snap_bound(range.1, |x| FloatCore::ceil(x / size) * size);
+        }
+
+        result
+    }
+
+    /// Rounds each start up and each end down to a multiple of the cell size of its axis, keeping
+    /// bound kinds. The result is held by the bbox, axes where start and end cross over are left
+    /// empty, going from start to start excluded. Cell sizes must be positive.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::{point, vector};
+    /// use pythagore::BBox;
+    ///
+    /// assert_eq!(
+    ///     BBox::from(point![0.3, -1.7]..point![4.2, 0.1]).snap_inward(&vector![1.0, 1.0]),
+    ///     BBox::from(point![1.0, -1.0]..point![4.0, 0.0])
+    /// );
+    /// ```
+    pub fn snap_inward(&self, cell: &SVector<N, D>) -> BBox<N, D> {
+        let mut result = *self;
+
+        for (idx, range) in result.iter_mut().enumerate() {
+            let size = unsafe { *cell.get_unchecked(idx) };
+
+            range.0 = snap_bound(range.0, |x| FloatCore::ceil(x / size) * size);
+            range.1 = snap_bound(range.1, |x| FloatCore::floor(x / size) * size);
+
+            if let (Included(start) | Excluded(start), Included(end) | Excluded(end)) = *range {
+                if start > end {
+                    *range = (Excluded(start), Excluded(start));
+                }
+            }
+        }
+
+        result
+    }
+}
+
+impl<const D: usize> BBox<f64, D> {
+    /// Computes the box of indexes of the grid cells covering the bbox. Cell i of an axis goes from
+    /// i·cell included to (i + 1)·cell excluded, so the result goes from floor(start / cell) to
+    /// ceil(end / cell) - 1 on excluded ends, and to floor(end / cell) on included ends, both included.
+    /// Cell sizes must be positive.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::{point, vector};
+    /// use pythagore::BBox;
+    ///
+    /// assert_eq!(
+    ///     BBox::from(point![0.3, -1.7]..point![4.2, 0.1]).to_cell_bbox(&vector![1.0, 1.0]),
+    ///     BBox::from(point![0, -2]..=point![4, 0])
+    /// );
+    /// ```
+    pub fn to_cell_bbox(&self, cell: &SVector<f64, D>) -> BBox<i64, D> {
+        let mut result = BBox::default();
+
+        for (idx, (range, size)) in self.iter().zip(cell.iter()).enumerate() {
+            result[idx] = (
+                match range.0 {
+                    Included(x) | Excluded(x) => Included(FloatCore::floor(x / size) as i64),
+                    Unbounded => Unbounded,
+                },
+                match range.1 {
+                    Included(x) => Included(FloatCore::floor(x / size) as i64),
+                    Excluded(x) => Included(FloatCore::ceil(x / size) as i64 - 1),
+                    Unbounded => Unbounded,
+                },
+            );
+        }
+
+        result
+    }
+
+    /// Rounds each start down and each end up to the nearest integer, keeping bound kinds, as
+    /// [`BBox::snap_outward`] does with unit cells. The result holds the bbox.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// assert_eq!(
+    ///     BBox::from(point![0.2, -1.5]..point![4.9, 3.0]).outer_int_hull(),
+    ///     BBox::from(point![0, -2]..point![5, 3])
+    /// );
+    /// ```
+    pub fn outer_int_hull(&self) -> BBox<i64, D> {
+        self.snap_outward(&SVector::repeat(1.0)).map_scalar(|x| x as i64)
+    }
+
+    /// Rounds each start up and each end down to the nearest integer, keeping bound kinds, as
+    /// [`BBox::snap_inward`] does with unit cells. The result is held by the bbox, and may be empty:
+    /// axes where start and end cross over go from start to start excluded.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// assert_eq!(
+    ///     BBox::from(point![0.2, -1.5]..point![4.9, 3.0]).inner_int_hull(),
+    ///     BBox::from(point![1, -1]..point![4, 3])
+    /// );
+    /// ```
+    pub fn inner_int_hull(&self) -> BBox<i64, D> {
+        self.snap_inward(&SVector::repeat(1.0)).map_scalar(|x| x as i64)
+    }
+}
+
+impl<const D: usize> BBox<i64, D> {
+    /// Converts bounds to floats, keeping their kinds. Exact for values up to 2^53 in magnitude.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// assert_eq!(
+    ///     BBox::from(point![0, -2]..point![5, 3]).to_float(),
+    ///     BBox::from(point![0.0, -2.0]..point![5.0, 3.0])
+    /// );
+    /// ```
+    pub fn to_float(&self) -> BBox<f64, D> {
+        self.map_scalar(|x| x as f64)
+    }
+}
+
+/// Applies f to the bound value, keeping its kind
+fn snap_bound<N: Copy>(bound: Bound<N>, f: impl Fn(N) -> N) -> Bound<N> {
+    match bound {
+        Included(x) => Included(f(x)),
+        Excluded(x) => Excluded(f(x)),
+        Unbounded => Unbounded,
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use na::{point, vector};
+    use crate::{Holds, IsRangeEmpty};
+    use super::*;
+
+    #[test]
+    fn test_snap_outward() {
+        let bbox = BBox::from([(Excluded(0.5), Included(2.5)), (Unbounded, Excluded(-3.0))]);
+
+        assert_eq!(
+            bbox.snap_outward(&vector![2.0, 0.25]),
+            BBox::from([(Excluded(0.0), Included(4.0)), (Unbounded, Excluded(-3.0))])
+        );
+    }
+
+    #[test]
+    fn test_snap_inward() {
+        let bbox = BBox::from(point![0.5, -1.0]..point![5.0, 1.7]);
+
+        assert_eq!(
+            bbox.snap_inward(&vector![2.0, 0.5]),
+            BBox::from(point![2.0, -1.0]..point![4.0, 1.5])
+        );
+    }
+
+    #[test]
+    fn test_snap_inward_cross_over() {
+        let bbox = BBox::from(point![0.3, 0.0]..point![0.7, 4.0]);
+        let snapped = bbox.snap_inward(&vector![1.0, 1.0]);
+
+        assert_eq!(snapped[0], (Excluded(1.0), Excluded(1.0)));
+        assert!(!snapped.holds(&point![1.0, 1.0]));
+    }
+
+    #[test]
+    fn test_to_cell_bbox() {
+        let bbox = BBox::from([(Included(-0.5), Excluded(3.0)), (Excluded(1.0), Included(7.5))]);
+
+        assert_eq!(
+            bbox.to_cell_bbox(&vector![0.5, 2.5]),
+            BBox::from(point![-1, 0]..=point![5, 3])
+        );
+        assert_eq!(
+            BBox::from(point![0.0, 0.0]..).to_cell_bbox(&vector![1.0, 1.0]),
+            BBox::from(point![0, 0]..)
+        );
+    }
+
+    #[test]
+    fn test_int_hulls() {
+        let bbox = BBox::from([(Included(0.2), Excluded(4.9)), (Excluded(-1.0), Excluded(5.0))]);
+
+        assert_eq!(bbox.outer_int_hull(), BBox::from([(Included(0), Excluded(5)), (Excluded(-1), Excluded(5))]));
+        assert_eq!(bbox.inner_int_hull(), BBox::from([(Included(1), Excluded(4)), (Excluded(-1), Excluded(5))]));
+    }
+
+    #[test]
+    fn test_integral_int_hulls() {
+        let bbox = BBox::from([(Included(-3.0), Excluded(2.0)), (Unbounded, Included(7.0))]);
+        let expected = BBox::from([(Included(-3), Excluded(2)), (Unbounded, Included(7))]);
+
+        assert_eq!(bbox.outer_int_hull(), expected);
+        assert_eq!(bbox.inner_int_hull(), expected);
+        assert_eq!(expected.to_float(), bbox);
+    }
+
+    #[test]
+    fn test_sliver_inner_int_hull() {
+        let bbox = BBox::from(point![0.2, 0.0]..=point![0.8, 3.0]);
+
+        assert!(bbox.inner_int_hull().is_range_empty());
+        assert_eq!(bbox.outer_int_hull(), BBox::from(point![0, 0]..=point![1, 3]));
+    }
+}
@@ -1,7 +1,34 @@
-use std::cmp::{max, min};
-use std::ops::Bound;
-use std::ops::Bound::{Excluded, Included, Unbounded};
-use na::{Point, Scalar};
+use core::cmp::{max, min, Ordering};
+use core::ops::Bound;
+use core::ops::Bound::{Excluded, Included, Unbounded};
+use na::{ClosedAdd, ClosedSub, Point, Scalar};
+use num_traits::{CheckedAdd, CheckedSub, One, Zero};
+
+/// Compares the distances from x to a and from x to b, without computing an overflowing difference.
+/// Returns None if values are not comparable (NaN).
+pub fn cmp_distances<N>(x: N, a: N, b: N) -> Option<Ordering>
+where
+    N: ClosedAdd + ClosedSub + Copy + PartialOrd + Zero
+{
+    let (lo, hi, swapped) = match a.partial_cmp(&b)? {
+        Ordering::Equal => return Some(Ordering::Equal),
+        Ordering::Less => (a, b, false),
+        Ordering::Greater => (b, a, true),
+    };
+
+    let res = if x <= lo {
+        Ordering::Less
+    } else if x >= hi {
+        Ordering::Greater
+    } else if x >= N::zero() {
+        // x - lo compared to hi - x, moving the term that may overflow to the other side
+        x.partial_cmp(&(hi - x + lo))?
+    } else {
+        (x - lo + x).partial_cmp(&hi)?
+    };
+
+    Some(if swapped { res.reverse() } else { res })
+}
 
 /// Compute greatest start bound
 pub fn max_bound<N: PartialOrd>(a: Bound<N>, b: Bound<N>) -> Bound<N> {
@@ -15,6 +42,56 @@ pub fn max_bound<N: PartialOrd>(a: Bound<N>, b: Bound<N>) -> Bound<N> {
     }
 }
 
+/// Compute greatest end bound
+pub fn max_end_bound<N: PartialOrd>(a: Bound<N>, b: Bound<N>) -> Bound<N> {
+    match (&a, &b) {
+        (Included(va), Included(vb)) |
+        (Excluded(va), Excluded(vb)) |
+        (Included(va), Excluded(vb)) => if va < vb { b } else { a },
+        (Excluded(va), Included(vb)) => if va <= vb { b } else { a },
+        (_, Unbounded) => b,
+        (Unbounded, _) => a,
+    }
+}
+
+/// Moves an excluded start bound to the first included lattice value, None if that overflows
+pub fn lattice_start<N: CheckedAdd + Copy + One>(bound: Bound<N>) -> Option<Bound<N>> {
+    match bound {
+        Excluded(x) => Some(Included(x.checked_add(&N::one())?)),
+        bound => Some(bound),
+    }
+}
+
+/// Moves an excluded end bound to the last included lattice value, None if that overflows
+pub fn lattice_end<N: CheckedSub + Copy + One>(bound: Bound<N>) -> Option<Bound<N>> {
+    match bound {
+        Excluded(x) => Some(Included(x.checked_sub(&N::one())?)),
+        bound => Some(bound),
+    }
+}
+
+/// Moves every coordinate of point to the next lattice value, None if that overflows
+pub fn next_lattice_point<N: CheckedAdd + Copy + One + Scalar, const D: usize>(point: &Point<N, D>) -> Option<Point<N, D>> {
+    let mut res = *point;
+
+    for x in res.iter_mut() {
+        *x = x.checked_add(&N::one())?;
+    }
+
+    Some(res)
+}
+
+/// Moves every coordinate of point to the previous lattice value, None if that overflows
+pub fn prev_lattice_point<N: CheckedSub + Copy + One + Scalar, const D: usize>(point: &Point<N, D>) -> Option<Point<N, D>> {
+    let mut res = *point;
+
+    for x in res.iter_mut() {
+        *x = x.checked_sub(&N::one())?;
+    }
+
+    Some(res)
+}
+
 /// Compute point with maximum coordinates
 pub fn max_point<N: Default + Copy + Ord + Scalar, const D: usize>(a: &Point<N, D>, b: &Point<N, D>) -> Point<N, D> {
     let mut coords = [N::default(); D];
@@ -41,6 +118,18 @@ pub fn min_bound<N: PartialOrd>(a: Bound<N>, b: Bound<N>) -> Bound<N> {
     }
 }
 
+/// Compute smallest start bound
+pub fn min_start_bound<N: PartialOrd>(a: Bound<N>, b: Bound<N>) -> Bound<N> {
+    match (&a, &b) {
+        (Included(va), Included(vb)) |
+        (Excluded(va), Excluded(vb)) |
+        (Included(va), Excluded(vb)) => if va <= vb { a } else { b },
+        (Excluded(va), Included(vb)) => if va < vb { a } else { b },
+        (_, Unbounded) => b,
+        (Unbounded, _) => a,
+    }
+}
+
 /// Compute point with minimum coordinates
 pub fn min_point<N: Default + Copy + Ord + Scalar, const D: usize>(a: &Point<N, D>, b: &Point<N, D>) -> Point<N, D> {
     let mut coords = [N::default(); D];
@@ -55,6 +144,18 @@ pub fn min_point<N: Default + Copy + Ord + Scalar, const D: usize>(a: &Point<N,
     Point::from(coords)
 }
 
+/// Returns the greatest of two values, ignoring a value that is not comparable to itself (NaN).
+/// If both values are NaN, the second one is returned.
+pub fn partial_max<N: PartialOrd>(a: N, b: N) -> N {
+    if b > a || a.partial_cmp(&a).is_none() { b } else { a }
+}
+
+/// Returns the smallest of two values, ignoring a value that is not comparable to itself (NaN).
+/// If both values are NaN, the second one is returned.
+pub fn partial_min<N: PartialOrd>(a: N, b: N) -> N {
+    if b < a || a.partial_cmp(&a).is_none() { b } else { a }
+}
+
 // Tests
 #[cfg(test)]
 mod tests {
@@ -73,6 +174,18 @@ mod tests {
         assert_eq!(max_bound(Unbounded, Included(5)), Included(5));
     }
 
+    #[test]
+    fn test_max_end_bound() {
+        assert_eq!(max_end_bound(Included(0), Included(5)), Included(5));
+        assert_eq!(max_end_bound(Included(0), Excluded(5)), Excluded(5));
+        assert_eq!(max_end_bound(Included(0), Excluded(0)), Included(0));
+        assert_eq!(max_end_bound(Excluded(0), Included(5)), Included(5));
+        assert_eq!(max_end_bound(Excluded(0), Included(0)), Included(0));
+        assert_eq!(max_end_bound(Excluded(0), Excluded(5)), Excluded(5));
+        assert_eq!(max_end_bound(Excluded(0), Unbounded), Unbounded);
+        assert_eq!(max_end_bound(Unbounded, Included(5)), Unbounded);
+    }
+
     #[test]
     fn test_max_point() {
         assert_eq!(max_point(&point![0, 5], &point![5, 0]), point![5, 5]);
@@ -90,8 +203,38 @@ mod tests {
         assert_eq!(min_bound(Unbounded, Included(5)), Included(5));
     }
 
+    #[test]
+    fn test_min_start_bound() {
+        assert_eq!(min_start_bound(Included(0), Included(5)), Included(0));
+        assert_eq!(min_start_bound(Included(0), Excluded(5)), Included(0));
+        assert_eq!(min_start_bound(Included(0), Excluded(0)), Included(0));
+        assert_eq!(min_start_bound(Excluded(0), Included(5)), Excluded(0));
+        assert_eq!(min_start_bound(Excluded(0), Included(0)), Included(0));
+        assert_eq!(min_start_bound(Excluded(0), Excluded(5)), Excluded(0));
+        assert_eq!(min_start_bound(Excluded(0), Unbounded), Unbounded);
+        assert_eq!(min_start_bound(Unbounded, Included(5)), Unbounded);
+    }
+
     #[test]
     fn test_min_point() {
         assert_eq!(min_point(&point![0, 5], &point![5, 0]), point![0, 0]);
     }
+
+    #[test]
+    fn test_partial_max() {
+        assert_eq!(partial_max(1.0, 2.0), 2.0);
+        assert_eq!(partial_max(2.0, 1.0), 2.0);
+        assert_eq!(partial_max(f64::NAN, 1.0), 1.0);
+        assert_eq!(partial_max(1.0, f64::NAN), 1.0);
+        assert!(partial_max(f64::NAN, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_partial_min() {
+        assert_eq!(partial_min(1.0, 2.0), 1.0);
+        assert_eq!(partial_min(2.0, 1.0), 1.0);
+        assert_eq!(partial_min(f64::NAN, 1.0), 1.0);
+        assert_eq!(partial_min(1.0, f64::NAN), 1.0);
+        assert!(partial_min(f64::NAN, f64::NAN).is_nan());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,274 @@
+use core::ops::Bound;
+use core::ops::Bound::{Excluded, Included, Unbounded};
+use na::{Point, Scalar};
+use num_traits::float::FloatCore;
+
+use crate::BBox;
+
+/// Scalar types that can be wrapped or clamped inside a range
+pub trait WrapScalar: Copy + PartialOrd + Scalar {
+    /// Returns start and length of the period held by given range, None if range is unbounded or empty.
+    /// On integer scalars the period covers the held lattice, on float scalars it goes from start to end.
+    /// Integer periods whose length does not fit in the scalar type (like a full-range box) also give None.
+    fn period(range: &(Bound<Self>, Bound<Self>)) -> Option<(Self, Self)>;
+
+    /// Returns first and last values held by given range, None if range is unbounded or empty.
+    /// On integer scalars these are lattice values, on float scalars they are start and end.
+    fn limits(range: &(Bound<Self>, Bound<Self>)) -> Option<(Self, Self)>;
+
+    /// Maps x inside the given period, by modular arithmetic
+    fn wrap(x: Self, start: Self, len: Self) -> Self;
+
+    /// Maps x to the nearest value between first and last
+    fn clamp(x: Self, first: Self, last: Self) -> Self;
+}
+
+macro_rules! wrap_scalar_int_impl {
+    ($($t:ty),*) => {$(
+        impl WrapScalar for $t {
+            fn period(range: &(Bound<$t>, Bound<$t>)) -> Option<($t, $t)> {
+                let (first, last) = Self::limits(range)?;
+                Some((first, last.checked_sub(first)?.checked_add(1)?))
+            }
+
+            fn limits(range: &(Bound<$t>, Bound<$t>)) -> Option<($t, $t)> {
+                let first = match range.0 {
+                    Included(x) => x,
+                    Excluded(x) => x.checked_add(1)?,
+                    Unbounded => return None,
+                };
+
+                let last = match range.1 {
+                    Included(x) => x,
+                    Excluded(x) => x.checked_sub(1)?,
+                    Unbounded => return None,
+                };
+
+                if last < first { None } else { Some((first, last)) }
+            }
+
+            fn wrap(x: $t, start: $t, len: $t) -> $t {
+                // Works on the unsigned distance to start, as x - start may overflow
+                let len = len.abs_diff(0);
+                let offset = x.abs_diff(start) % len;
+                let offset = if x >= start || offset == 0 { offset } else { len - offset };
+
+                // Result lies inside the period, so the two's complement addition is exact
+                start.wrapping_add(offset as $t)
+            }
+
+            #[inline]
+            fn clamp(x: $t, first: $t, last: $t) -> $t {
+                x.clamp(first, last)
+            }
+        }
+    )*};
+}
+
+macro_rules! wrap_scalar_float_impl {
+    ($($t:ty),*) => {$(
+        impl WrapScalar for $t {
+            #[inline]
+            fn period(range: &(Bound<$t>, Bound<$t>)) -> Option<($t, $t)> {
+                let (start, end) = Self::limits(range)?;
+                Some((start, end - start))
+            }
+
+            fn limits(range: &(Bound<$t>, Bound<$t>)) -> Option<($t, $t)> {
+                match *range {
+                    (Included(start), Included(end)) if start <= end => Some((start, end)),
+                    (Included(start) | Excluded(start), Included(end) | Excluded(end)) if start < end => Some((start, end)),
+                    _ => None,
+                }
+            }
+
+            fn wrap(x: $t, start: $t, len: $t) -> $t {
+                if len == 0.0 {
+                    return start;
+                }
+
+                let res = x - len * FloatCore::floor((x - start) / len);
+
+                // Rounding may push result onto the end of the period
+                if res < start + len { res } else { start }
+            }
+
+            #[inline]
+            fn clamp(x: $t, first: $t, last: $t) -> $t {
+                x.clamp(first, last)
+            }
+        }
+    )*};
+}
+
+wrap_scalar_int_impl!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+wrap_scalar_float_impl!(f32, f64);
+
+impl<N: WrapScalar, const D: usize> BBox<N, D> {
+    /// Maps given point inside bbox, as if bbox was a torus. Returns None if bbox is unbounded or empty.
+    /// On integer scalars each axis wraps around its held lattice, on float scalars it wraps from
+    /// start to end, so a coordinate on the end is mapped to the start. Integer axes holding more
+    /// values than the scalar maximum (like a full-range box) also give None.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// let bbox = BBox::from(point![0, 0]..point![5, 5]);
+    ///
+    /// assert_eq!(bbox.wrap_point(&point![7, -1]), Some(point![2, 4]));
+    /// ```
+    pub fn wrap_point(&self, point: &Point<N, D>) -> Option<Point<N, D>> {
+        self.map_point(point, N::period, N::wrap)
+    }
+
+    /// Maps given point to the nearest point inside bbox. Returns None if bbox is unbounded or empty.
+    /// On float scalars, coordinates are clamped between start and end whatever the bounds kinds.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// let bbox = BBox::from(point![0, 0]..point![5, 5]);
+    ///
+    /// assert_eq!(bbox.clamp_point(&point![7, -1]), Some(point![4, 0]));
+    /// ```
+    pub fn clamp_point(&self, point: &Point<N, D>) -> Option<Point<N, D>> {
+        self.map_point(point, N::limits, N::clamp)
+    }
+
+    /// Maps each coordinate of point using the values computed from the range of its axis
+    fn map_point(
+        &self,
+        point: &Point<N, D>,
+        params: impl Fn(&(Bound<N>, Bound<N>)) -> Option<(N, N)>,
+        f: impl Fn(N, N, N) -> N
+    ) -> Option<Point<N, D>> {
+        let mut res = *point;
+
+        for (idx, x) in res.iter_mut().enumerate() {
+            let (a, b) = params(&self[idx])?;
+            *x = f(*x, a, b);
+        }
+
+        Some(res)
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use na::point;
+    use super::*;
+
+    mod wrap_point {
+        use super::*;
+
+        #[test]
+        fn test_int_bbox() {
+            let bbox = BBox::from(point![0, -2]..point![5, 3]);
+
+            assert_eq!(bbox.wrap_point(&point![2, 1]), Some(point![2, 1]));
+            assert_eq!(bbox.wrap_point(&point![5, 3]), Some(point![0, -2]));
+            assert_eq!(bbox.wrap_point(&point![-1, -3]), Some(point![4, 2]));
+            assert_eq!(bbox.wrap_point(&point![-11, 14]), Some(point![4, -1]));
+        }
+
+        #[test]
+        fn test_int_bound_kinds() {
+            let bbox = BBox::from([(Excluded(0), Included(3)), (Included(-2), Included(-2))]);
+
+            assert_eq!(bbox.wrap_point(&point![0, 5]), Some(point![3, -2]));
+            assert_eq!(bbox.wrap_point(&point![4, -7]), Some(point![1, -2]));
+        }
+
+        #[test]
+        fn test_float_bbox() {
+            let bbox = BBox::from(point![0.0, -1.0]..point![2.0, 1.0]);
+
+            assert_eq!(bbox.wrap_point(&point![0.5, 0.5]), Some(point![0.5, 0.5]));
+            assert_eq!(bbox.wrap_point(&point![2.0, 1.0]), Some(point![0.0, -1.0]));
+            assert_eq!(bbox.wrap_point(&point![-0.5, -1.5]), Some(point![1.5, 0.5]));
+            assert_eq!(bbox.wrap_point(&point![5.0, 4.5]), Some(point![1.0, 0.5]));
+        }
+
+        #[test]
+        fn test_single_point_bbox() {
+            assert_eq!(BBox::from(point![1, 2]..=point![1, 2]).wrap_point(&point![-5, 8]), Some(point![1, 2]));
+            assert_eq!(BBox::from(point![1.0, 2.0]..=point![1.0, 2.0]).wrap_point(&point![-5.0, 8.0]), Some(point![1.0, 2.0]));
+        }
+
+        #[test]
+        fn test_unsigned_bbox() {
+            let bbox = BBox::from(point![10u32]..point![20u32]);
+
+            assert_eq!(bbox.wrap_point(&point![5]), Some(point![15]));
+            assert_eq!(bbox.wrap_point(&point![0]), Some(point![10]));
+            assert_eq!(bbox.wrap_point(&point![25]), Some(point![15]));
+            assert_eq!(bbox.wrap_point(&point![u32::MAX]), Some(point![15]));
+        }
+
+        #[test]
+        fn test_extreme_int_values() {
+            let bbox = BBox::from(point![-5, 100]..point![5, 103]);
+
+            assert_eq!(bbox.wrap_point(&point![i32::MIN, i32::MAX]), Some(point![2, 100]));
+            assert_eq!(bbox.wrap_point(&point![i32::MAX, i32::MIN]), Some(point![-3, 100]));
+
+            let bbox = BBox::from([(Included(-100i8), Included(20i8))]);
+
+            assert_eq!(bbox.wrap_point(&point![127]), Some(point![6]));
+            assert_eq!(bbox.wrap_point(&point![-128]), Some(point![-7]));
+        }
+
+        #[test]
+        fn test_full_range_bbox() {
+            assert_eq!(BBox::from([(Included(i8::MIN), Included(i8::MAX))]).wrap_point(&point![0]), None);
+            assert_eq!(BBox::from([(Included(-100i8), Included(100i8))]).wrap_point(&point![0]), None);
+            assert_eq!(BBox::from([(Included(0u8), Included(u8::MAX))]).wrap_point(&point![0]), None);
+        }
+
+        #[test]
+        fn test_unbounded_or_empty_bbox() {
+            assert_eq!(BBox::from(point![0, 0]..).wrap_point(&point![1, 1]), None);
+            assert_eq!(BBox::from(point![0, 0]..point![0, 5]).wrap_point(&point![1, 1]), None);
+            assert_eq!(BBox::from(point![0.0, 0.0]..point![0.0, 5.0]).wrap_point(&point![1.0, 1.0]), None);
+        }
+    }
+
+    mod clamp_point {
+        use super::*;
+
+        #[test]
+        fn test_int_bbox() {
+            let bbox = BBox::from(point![0, -2]..point![5, 3]);
+
+            assert_eq!(bbox.clamp_point(&point![2, 1]), Some(point![2, 1]));
+            assert_eq!(bbox.clamp_point(&point![5, 3]), Some(point![4, 2]));
+            assert_eq!(bbox.clamp_point(&point![-1, -3]), Some(point![0, -2]));
+        }
+
+        #[test]
+        fn test_float_bbox() {
+            let bbox = BBox::from(point![0.0, -1.0]..point![2.0, 1.0]);
+
+            assert_eq!(bbox.clamp_point(&point![0.5, 0.5]), Some(point![0.5, 0.5]));
+            assert_eq!(bbox.clamp_point(&point![3.0, -1.5]), Some(point![2.0, -1.0]));
+        }
+
+        #[test]
+        fn test_full_range_bbox() {
+            assert_eq!(BBox::from([(Included(-100i8), Included(100i8))]).clamp_point(&point![120]), Some(point![100]));
+            assert_eq!(BBox::from([(Included(i8::MIN), Included(i8::MAX))]).clamp_point(&point![-5]), Some(point![-5]));
+            assert_eq!(BBox::from([(Included(0u8), Included(u8::MAX))]).clamp_point(&point![200]), Some(point![200]));
+            assert_eq!(BBox::from([(Excluded(0u8), Excluded(u8::MAX))]).clamp_point(&point![255]), Some(point![254]));
+        }
+
+        #[test]
+        fn test_unbounded_bbox() {
+            assert_eq!(BBox::from(point![0, 0]..).clamp_point(&point![1, 1]), None);
+        }
+    }
+}
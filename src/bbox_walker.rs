@@ -1,24 +1,88 @@
+mod boundary_iter;
 mod iter;
+mod morton_iter;
+#[cfg(feature = "rayon")]
+mod par_iter;
+mod walk_iter;
+mod walk_order;
 
-use std::ops::AddAssign;
-use na::{Point, Scalar};
-use num_traits::One;
-use crate::bbox_walker::iter::Iter;
+use core::ops::AddAssign;
+use na::{ClosedAdd, ClosedSub, Point, Scalar};
+use num_traits::{CheckedAdd, CheckedSub, NumCast, One, Zero};
+use crate::{BBox, Holds, Intersection, IsRangeEmpty, Walkable};
 
-/// Generates points inside a bbox, in xy order.
-#[derive(Clone, Copy, Debug)]
+pub use boundary_iter::BoundaryIter;
+pub use iter::Iter;
+pub use morton_iter::MortonIter;
+#[cfg(feature = "rayon")]
+pub use par_iter::ParIter;
+pub use walk_iter::WalkIter;
+pub use walk_order::WalkOrder;
+
+/// Builds a walker over the part of range held by clamp, allowing to walk unbounded ranges.
+/// Returns None if that part is unbounded or empty.
+///
+/// # Example
+/// ```
+/// use nalgebra::point;
+/// use pythagore::BBox;
+/// use pythagore::bbox_walker::walk_clamped;
+///
+/// let viewport = BBox::from(point![-1, -1]..point![2, 2]);
+///
+/// assert_eq!(walk_clamped(&(point![0, 0]..), &viewport).unwrap().iter().count(), 4);
+/// ```
+pub fn walk_clamped<N, R, const D: usize>(range: &R, clamp: &BBox<N, D>) -> Option<BBoxWalker<N, D>>
+where
+    N: CheckedAdd + CheckedSub + ClosedAdd + ClosedSub + Copy + One + PartialOrd + Scalar + Zero,
+    R: Intersection<BBox<N, D>, Output = BBox<N, D>>
+{
+    let bbox = range.intersection(clamp);
+
+    if bbox.is_range_empty() {
+        None
+    } else {
+        bbox.walk().ok()
+    }
+}
+
+/// Generates points inside a bbox, in xy order unless another [`WalkOrder`] is given.
+/// A walker whose first point is greater than its last point on any axis is empty, as are walkers
+/// built from empty bboxes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct BBoxWalker<N: Scalar, const D: usize> {
     first: Point<N, D>,
     last: Point<N, D>,
+    order: WalkOrder,
 }
 
 impl<N: Scalar, const D: usize> BBoxWalker<N, D> {
     /// Builds a BBox Walker, moving inside a bbox going from first to last included.
     /// Uses a default step size of 1
     pub fn new(first: Point<N, D>, last: Point<N, D>) -> BBoxWalker<N, D> {
+        BBoxWalker::with_order(first, last, WalkOrder::RowMajor)
+    }
+
+    /// Builds a BBox Walker, moving inside a bbox going from first to last included, in given order.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBoxWalker;
+    /// use pythagore::bbox_walker::WalkOrder;
+    ///
+    /// let walker = BBoxWalker::with_order(point![0, 0], point![1, 1], WalkOrder::ColumnMajor);
+    ///
+    /// assert_eq!(
+    ///     walker.iter().collect::<Vec<_>>(),
+    ///     vec![point![0, 0], point![1, 0], point![0, 1], point![1, 1]]
+    /// );
+    /// ```
+    pub fn with_order(first: Point<N, D>, last: Point<N, D>, order: WalkOrder) -> BBoxWalker<N, D> {
         BBoxWalker {
             first,
-            last
+            last,
+            order,
         }
     }
 
@@ -32,48 +96,477 @@ impl<N: Scalar, const D: usize> BBoxWalker<N, D> {
         &self.last
     }
 
+    /// Order of walked points
+    pub fn order(&self) -> WalkOrder {
+        self.order
+    }
+
+    /// Returns true if walker walks through no point
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBoxWalker;
+    ///
+    /// assert!(!BBoxWalker::new(point![0, 0], point![0, 0]).is_empty());
+    /// assert!(BBoxWalker::new(point![0, 2], point![2, 0]).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool
+    where
+        N: PartialOrd
+    {
+        self.first.iter().zip(self.last.iter())
+            .any(|(first, last)| first > last)
+    }
+
     /// Returns iterator on walked points
     #[inline]
-    pub fn iter(&self) -> Iter<'_, N, D> {
+    pub fn iter(&self) -> Iter<'_, N, D>
+    where
+        N: Copy
+    {
         Iter::new(self)
     }
 
+    /// Returns iterator on walked points, starting at given point if walked through, or at the first
+    /// walked point coming after it (see [`BBoxWalker::next`]). Allows resuming a walk from a persisted
+    /// [`Iter::current`] point, by skipping it.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBoxWalker;
+    ///
+    /// let walker = BBoxWalker::new(point![0, 0], point![2, 2]);
+    ///
+    /// assert_eq!(walker.iter_from(&point![2, 1]).collect::<Vec<_>>(), vec![point![2, 1], point![2, 2]]);
+    /// assert_eq!(walker.iter_from(&point![1, 5]).next(), Some(point![2, 0]));
+    /// ```
+    pub fn iter_from(&self, start: &Point<N, D>) -> Iter<'_, N, D>
+    where
+        N: AddAssign + Copy + One + Ord
+    {
+        let first = if self.holds(start) { Some(*start) } else { self.next(start) };
+        Iter::starting_at(self, first)
+    }
+
+    /// Returns iterator on the boundary points of the walker, having at least one coordinate equal to
+    /// the first or last value of its axis. Points are yielded once, in row major order whatever the
+    /// walker order, and interior points are skipped without being walked through.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBoxWalker;
+    ///
+    /// let walker = BBoxWalker::new(point![0, 0], point![2, 2]);
+    ///
+    /// assert_eq!(walker.iter_boundary().count(), 8);
+    /// assert!(walker.iter_boundary().all(|pt| pt != point![1, 1]));
+    /// ```
+    #[inline]
+    pub fn iter_boundary(&self) -> BoundaryIter<N, D>
+    where
+        N: Copy + PartialOrd
+    {
+        BoundaryIter::new(self)
+    }
+
+    /// Returns iterator on the points of one face of the walker, having the first (side false) or last
+    /// (side true) value on the given axis. Points are yielded in walker order, empty walkers have empty faces.
+    ///
+    /// # Panics
+    /// Panics if axis is out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBoxWalker;
+    ///
+    /// let walker = BBoxWalker::new(point![0, 0], point![2, 2]);
+    ///
+    /// assert_eq!(walker.iter_face(0, true).collect::<Vec<_>>(), vec![point![2, 0], point![2, 1], point![2, 2]]);
+    /// ```
+    pub fn iter_face(&self, axis: usize, side: bool) -> WalkIter<N, D>
+    where
+        N: Copy + PartialOrd
+    {
+        assert!(axis < D, "Axis index out of bounds");
+
+        if self.is_empty() {
+            return WalkIter::new(*self);
+        }
+
+        let mut first = self.first;
+        let mut last = self.last;
+
+        if side {
+            first[axis] = last[axis];
+        } else {
+            last[axis] = first[axis];
+        }
+
+        WalkIter::new(BBoxWalker::with_order(first, last, self.order))
+    }
+
+    /// Returns parallel iterator on walked points, None if the point count does not fit in an usize
+    /// (see [`BBoxWalker::point_count`])
+    #[cfg(feature = "rayon")]
+    #[inline]
+    pub fn par_iter(&self) -> Option<ParIter<'_, N, D>>
+    where
+        N: Copy + NumCast + Ord
+    {
+        ParIter::new(self)
+    }
+
     /// Computes next point, if exists from "from" point.
+    /// "from" may be any point, the result is the first walked point coming after it in walk order.
     pub fn next(&self, from: &Point<N, D>) -> Option<Point<N, D>>
     where
         N: AddAssign + Copy + One + Ord
     {
-        if from == &self.last || unsafe { from.get_unchecked(0) > self.last.get_unchecked(0) } {
-            return None;
-        }
+        // Search last axis that can be incremented, while all previous ones are inside the walker
+        let mut axis = None;
 
-        let mut next = self.first;
-        let mut addable: Option<usize> = None;
+        for (rank, idx) in self.axis_order().enumerate() {
+            let v = unsafe { from.get_unchecked(idx) };
+            let first = unsafe { self.first.get_unchecked(idx) };
+            let last = unsafe { self.last.get_unchecked(idx) };
+
+            if v < last {
+                axis = Some((rank, idx));
+            }
 
-        for (idx, v) in from.iter().enumerate() {
-            if v < unsafe { self.first.get_unchecked(idx) } {
+            if v < first || v > last {
                 break;
-            } else if v < unsafe { self.last.get_unchecked(idx) } {
-                unsafe { *next.get_unchecked_mut(idx) = *v };
-                addable = Some(idx);
-
-                if idx == D - 1 {
-                    unsafe { *next.get_unchecked_mut(idx) += N::one() };
-                }
-            } else if let Some(back) = addable {
-                unsafe { *next.get_unchecked_mut(back) += N::one() };
-
-                return Some(next);
-            } else {
-                unsafe { *next.get_unchecked_mut(idx) = *self.last.get_unchecked(idx) };
             }
         }
 
+        let (rank, axis) = axis?;
+        let mut next = self.first;
+
+        for idx in self.axis_order().take(rank) {
+            unsafe { *next.get_unchecked_mut(idx) = *from.get_unchecked(idx) };
+        }
+
+        let v = unsafe { *from.get_unchecked(axis) };
+
+        if v >= unsafe { *self.first.get_unchecked(axis) } {
+            unsafe { *next.get_unchecked_mut(axis) = v };
+            // v < last, so this cannot overflow
+            unsafe { *next.get_unchecked_mut(axis) += N::one() };
+        }
+
         Some(next)
     }
+
+    /// Number of points walked through, None if it does not fit in an usize
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBoxWalker;
+    ///
+    /// assert_eq!(BBoxWalker::new(point![0, 0], point![2, 3]).point_count(), Some(12));
+    /// assert_eq!(BBoxWalker::new(point![0, 0], point![u64::MAX, u64::MAX]).point_count(), None);
+    /// ```
+    pub fn point_count(&self) -> Option<usize>
+    where
+        N: Copy + NumCast + Ord
+    {
+        if self.is_empty() {
+            return Some(0);
+        }
+
+        (0..D).try_fold(1usize, |count, idx| count.checked_mul(self.axis_size(idx)?))
+    }
+
+    /// Computes the n-th walked point (starting at 0), without walking through the previous ones.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBoxWalker;
+    ///
+    /// let walker = BBoxWalker::new(point![0, 0], point![2, 2]);
+    ///
+    /// assert_eq!(walker.nth_point(4), Some(point![1, 1]));
+    /// assert_eq!(walker.nth_point(9), None);
+    /// ```
+    pub fn nth_point(&self, n: usize) -> Option<Point<N, D>>
+    where
+        N: Copy + NumCast + Ord
+    {
+        let mut point = self.first;
+        let mut rem = n;
+
+        for idx in self.axis_order().rev() {
+            let size = self.axis_size(idx)?;
+            let x = unsafe { point.get_unchecked_mut(idx) };
+
+            *x = add_offset(*x, rem % size)?;
+            rem /= size;
+        }
+
+        if rem == 0 { Some(point) } else { None }
+    }
+
+    /// Computes the index of the given point in the walk order. Inverse of [`BBoxWalker::nth_point`].
+    /// Returns None if the point is not walked through.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBoxWalker;
+    ///
+    /// let walker = BBoxWalker::new(point![0, 0], point![2, 2]);
+    ///
+    /// assert_eq!(walker.position_of(&point![1, 1]), Some(4));
+    /// assert_eq!(walker.position_of(&point![3, 1]), None);
+    /// ```
+    pub fn position_of(&self, point: &Point<N, D>) -> Option<usize>
+    where
+        N: Copy + NumCast + Ord
+    {
+        let mut position = 0usize;
+
+        for idx in self.axis_order() {
+            let x = unsafe { point.get_unchecked(idx) };
+            let first = unsafe { *self.first.get_unchecked(idx) };
+            let last = unsafe { *self.last.get_unchecked(idx) };
+
+            if *x < first || *x > last {
+                return None;
+            }
+
+            position = position.checked_mul(self.axis_size(idx)?)?.checked_add(offset_of(first, *x)?)?;
+        }
+
+        Some(position)
+    }
+
+    /// Axes from the slowest changing to the fastest one, in row or column major order
+    fn axis_order(&self) -> impl DoubleEndedIterator<Item = usize> {
+        let reversed = self.order == WalkOrder::ColumnMajor;
+
+        (0..D).map(move |rank| if reversed { D - 1 - rank } else { rank })
+    }
+
+    /// Returns iterator on walked points in Morton order (Z-order curve), whatever the walker order.
+    /// Codes interleave the bits of the offsets from the first point, at each bit level the first axis
+    /// holds the most significant bit. Boxes which sizes are not powers of two are walked as if they
+    /// were padded, skipping the missing points. Returns None if the point count does not fit in an usize.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBoxWalker;
+    ///
+    /// let walker = BBoxWalker::new(point![0, 0], point![1, 2]);
+    ///
+    /// assert_eq!(
+    ///     walker.iter_morton().unwrap().collect::<Vec<_>>(),
+    ///     vec![point![0, 0], point![0, 1], point![1, 0], point![1, 1], point![0, 2], point![1, 2]]
+    /// );
+    /// ```
+    #[inline]
+    pub fn iter_morton(&self) -> Option<MortonIter<'_, N, D>>
+    where
+        N: Copy + NumCast + Ord
+    {
+        MortonIter::new(self)
+    }
+
+    /// Computes the n-th walked point in Morton order (see [`BBoxWalker::iter_morton`]), by descending
+    /// the bit levels of the offsets.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBoxWalker;
+    ///
+    /// let walker = BBoxWalker::new(point![0, 0], point![3, 3]);
+    ///
+    /// assert_eq!(walker.nth_morton_point(6), Some(point![1, 2]));
+    /// ```
+    pub fn nth_morton_point(&self, n: usize) -> Option<Point<N, D>>
+    where
+        N: Copy + NumCast + Ord
+    {
+        let (sizes, bits) = self.morton_layout()?;
+
+        if n >= self.point_count()? {
+            return None;
+        }
+
+        let mut prefix = [0; D];
+        let mut fixed = bits;
+        let mut rem = n;
+
+        for (idx, level) in morton_bits(&bits) {
+            fixed[idx] = level;
+            let count = morton_count(&sizes, &prefix, &fixed);
+
+            if rem >= count {
+                rem -= count;
+                prefix[idx] |= 1 << level;
+            }
+        }
+
+        let mut point = self.first;
+
+        for (idx, offset) in prefix.iter().enumerate() {
+            let x = unsafe { point.get_unchecked_mut(idx) };
+            *x = add_offset(*x, *offset)?;
+        }
+
+        Some(point)
+    }
+
+    /// Computes the index of the given point in Morton order, by counting points with lower codes.
+    /// Inverse of [`BBoxWalker::nth_morton_point`], returns None if the point is not walked through.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBoxWalker;
+    ///
+    /// let walker = BBoxWalker::new(point![0, 0], point![3, 3]);
+    ///
+    /// assert_eq!(walker.morton_position_of(&point![1, 2]), Some(6));
+    /// ```
+    pub fn morton_position_of(&self, point: &Point<N, D>) -> Option<usize>
+    where
+        N: Copy + NumCast + Ord
+    {
+        let (sizes, bits) = self.morton_layout()?;
+        let mut offsets = [0; D];
+
+        for (idx, offset) in offsets.iter_mut().enumerate() {
+            let x = unsafe { *point.get_unchecked(idx) };
+            let first = unsafe { *self.first.get_unchecked(idx) };
+            let last = unsafe { *self.last.get_unchecked(idx) };
+
+            if x < first || x > last {
+                return None;
+            }
+
+            *offset = offset_of(first, x)?;
+        }
+
+        let mut prefix = [0; D];
+        let mut fixed = bits;
+        let mut position = 0;
+
+        for (idx, level) in morton_bits(&bits) {
+            fixed[idx] = level;
+
+            if (offsets[idx] >> level) & 1 == 1 {
+                position += morton_count(&sizes, &prefix, &fixed);
+                prefix[idx] |= 1 << level;
+            }
+        }
+
+        Some(position)
+    }
+
+    /// Sizes of each axis, with the number of bits needed to store offsets along them.
+    /// None if the walker is empty or if its point count does not fit in an usize.
+    pub(crate) fn morton_layout(&self) -> Option<([usize; D], [u32; D])>
+    where
+        N: Copy + NumCast + Ord
+    {
+        self.point_count()?;
+
+        let mut sizes = [0; D];
+        let mut bits = [0; D];
+
+        for idx in 0..D {
+            sizes[idx] = self.axis_size(idx)?;
+            bits[idx] = usize::BITS - (sizes[idx] - 1).leading_zeros();
+        }
+
+        Some((sizes, bits))
+    }
+
+    /// Number of points walked through on the given axis, None if the walker is empty on that axis
+    /// or if it does not fit in an usize
+    fn axis_size(&self, idx: usize) -> Option<usize>
+    where
+        N: Copy + NumCast + Ord
+    {
+        let first = unsafe { *self.first.get_unchecked(idx) };
+        let last = unsafe { *self.last.get_unchecked(idx) };
+
+        if last < first {
+            None
+        } else {
+            offset_of(first, last)?.checked_add(1)
+        }
+    }
+}
+
+/// Distance from first to x (x >= first), computed without overflowing the scalar type
+fn offset_of<N: NumCast>(first: N, x: N) -> Option<usize> {
+    let offset = match (first.to_i128(), x.to_i128()) {
+        (Some(first), Some(x)) => x.abs_diff(first),
+        _ => x.to_u128()?.checked_sub(first.to_u128()?)?,
+    };
+
+    usize::try_from(offset).ok()
+}
+
+/// Moves first by the given offset, computed without overflowing the scalar type
+fn add_offset<N: NumCast>(first: N, offset: usize) -> Option<N> {
+    match first.to_i128() {
+        Some(first) => N::from(first.checked_add(i128::try_from(offset).ok()?)?),
+        None => N::from(first.to_u128()?.checked_add(offset as u128)?),
+    }
+}
+
+/// Bits of morton codes, from the most significant one, as (axis, level) pairs
+fn morton_bits<const D: usize>(bits: &[u32; D]) -> impl DoubleEndedIterator<Item = (usize, u32)> + '_ {
+    let max = bits.iter().copied().max().unwrap_or(0);
+
+    (0..max).rev()
+        .flat_map(move |level| (0..D).filter(move |idx| level < bits[*idx]).map(move |idx| (idx, level)))
+}
+
+/// Number of points whose offsets start with the given prefix, bits under the fixed levels being free
+fn morton_count<const D: usize>(sizes: &[usize; D], prefix: &[usize; D], fixed: &[u32; D]) -> usize {
+    (0..D)
+        .map(|idx| {
+            let end = prefix[idx].saturating_add(1usize.checked_shl(fixed[idx]).unwrap_or(usize::MAX));
+            end.min(sizes[idx]).saturating_sub(prefix[idx])
+        })
+        .product()
 }
 
 // Utils
+/// Checks if walker walks through given point
+///
+/// # Example
+/// ```
+/// use nalgebra::point;
+/// use pythagore::{BBoxWalker, Holds};
+///
+/// assert!(BBoxWalker::new(point![0, 0], point![2, 2]).holds(&point![2, 1]));
+/// ```
+impl<N: PartialOrd + Scalar, const D: usize> Holds<Point<N, D>> for BBoxWalker<N, D> {
+    fn holds(&self, object: &Point<N, D>) -> bool {
+        object.iter().enumerate()
+            .all(|(idx, x)| unsafe { self.first.get_unchecked(idx) <= x && x <= self.last.get_unchecked(idx) })
+    }
+}
+
+impl<N: PartialOrd + Scalar, const D: usize> IsRangeEmpty for BBoxWalker<N, D> {
+    #[inline]
+    fn is_range_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
 impl<'a, N: AddAssign + Copy + One + Ord + Scalar, const D: usize> IntoIterator for &'a BBoxWalker<N, D> {
     type Item = Point<N, D>;
     type IntoIter = Iter<'a, N, D>;
@@ -84,10 +577,21 @@ impl<'a, N: AddAssign + Copy + One + Ord + Scalar, const D: usize> IntoIterator
     }
 }
 
+impl<N: AddAssign + Copy + One + Ord + Scalar, const D: usize> IntoIterator for BBoxWalker<N, D> {
+    type Item = Point<N, D>;
+    type IntoIter = WalkIter<N, D>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        WalkIter::new(self)
+    }
+}
+
 // Tests
 #[cfg(test)]
 mod tests {
     use na::point;
+    use crate::BBox;
     use super::*;
 
     #[test]
@@ -105,6 +609,16 @@ mod tests {
         assert_eq!(walker.next(&point![2, 2]), None);
     }
 
+    #[test]
+    fn test_next_on_3d_range() {
+        let walker = BBoxWalker::new(point![0, 0, 0], point![1, 1, 1]);
+
+        assert_eq!(walker.next(&point![0, 0, 1]), Some(point![0, 1, 0]));
+        assert_eq!(walker.next(&point![0, 1, 0]), Some(point![0, 1, 1]));
+        assert_eq!(walker.next(&point![0, 1, 1]), Some(point![1, 0, 0]));
+        assert_eq!(walker.next(&point![1, 1, 1]), None);
+    }
+
     #[test]
     fn test_iterator() {
         let walker = BBoxWalker::new(point![0, 0], point![2, 2]);
@@ -122,6 +636,305 @@ mod tests {
         assert_eq!(iter.next(), None);
     }
 
+    #[test]
+    fn test_into_iterator() {
+        let walker = BBoxWalker::new(point![0, 0], point![1, 1]);
+
+        assert_eq!(
+            walker.into_iter().collect::<Vec<_>>(),
+            vec![point![0, 0], point![0, 1], point![1, 0], point![1, 1]]
+        );
+    }
+
+    #[test]
+    fn test_walk_clamped() {
+        let viewport = BBox::from(point![-1, -1]..=point![1, 1]);
+        let walker = walk_clamped(&(point![0, 0]..), &BBox::from(point![0, 0]..point![3, 3])).unwrap();
+
+        assert_eq!(walker.iter().count(), 9);
+        assert_eq!(walk_clamped(&(..=point![0, 0]), &viewport).unwrap().iter().count(), 4);
+        assert_eq!(walk_clamped(&(..point![0, 0]), &viewport).unwrap().iter().count(), 1);
+        assert!(walk_clamped(&(point![2, 2]..), &viewport).is_none());
+        assert!(walk_clamped(&(point![0, 0]..), &BBox::from(point![0, 0]..)).is_none());
+    }
+
+    mod iter_from {
+        use super::*;
+
+        #[test]
+        fn test_middle_of_box() {
+            let walker = BBoxWalker::new(point![0, 0], point![2, 2]);
+
+            assert_eq!(
+                walker.iter_from(&point![1, 1]).collect::<Vec<_>>(),
+                walker.iter().skip(4).collect::<Vec<_>>()
+            );
+        }
+
+        #[test]
+        fn test_around_box() {
+            let walker = BBoxWalker::new(point![0, 0], point![2, 2]);
+
+            assert_eq!(walker.iter_from(&point![2, 2]).collect::<Vec<_>>(), vec![point![2, 2]]);
+            assert_eq!(walker.iter_from(&point![2, 3]).next(), None);
+            assert_eq!(walker.iter_from(&point![3, 0]).next(), None);
+            assert_eq!(walker.iter_from(&point![-1, 5]).count(), 9);
+            assert_eq!(walker.iter_from(&point![1, -1]).next(), Some(point![1, 0]));
+        }
+
+        #[test]
+        fn test_resume() {
+            let walker = BBoxWalker::new(point![0, 0, 0], point![2, 3, 1]);
+            let mut iter = walker.iter();
+
+            assert_eq!(iter.current(), None);
+
+            let head: Vec<_> = iter.by_ref().take(7).collect();
+            let cursor = *iter.current().unwrap();
+
+            assert_eq!(cursor, head[6]);
+
+            let mut resumed = walker.iter_from(&cursor);
+            resumed.next();
+
+            assert_eq!(resumed.collect::<Vec<_>>(), walker.iter().skip(7).collect::<Vec<_>>());
+        }
+
+        #[test]
+        fn test_empty_walker() {
+            let walker = BBoxWalker::new(point![0, 2], point![2, 0]);
+
+            assert_eq!(walker.iter_from(&point![1, 1]).next(), None);
+        }
+    }
+
+    mod walk_order {
+        use super::*;
+
+        #[test]
+        fn test_column_major() {
+            let rows = BBoxWalker::new(point![0, 0], point![2, 1]);
+            let columns = BBoxWalker::with_order(point![0, 0], point![1, 2], WalkOrder::ColumnMajor);
+
+            assert_eq!(
+                columns.iter().collect::<Vec<_>>(),
+                rows.iter().map(|pt| point![pt.y, pt.x]).collect::<Vec<_>>()
+            );
+        }
+
+        #[test]
+        fn test_morton() {
+            let walker = BBoxWalker::new(point![0, 0], point![3, 3]);
+
+            assert_eq!(
+                walker.iter_morton().unwrap().collect::<Vec<_>>(),
+                vec![
+                    point![0, 0], point![0, 1], point![1, 0], point![1, 1],
+                    point![0, 2], point![0, 3], point![1, 2], point![1, 3],
+                    point![2, 0], point![2, 1], point![3, 0], point![3, 1],
+                    point![2, 2], point![2, 3], point![3, 2], point![3, 3],
+                ]
+            );
+        }
+
+        #[test]
+        fn test_morton_non_power_of_two() {
+            let walker = BBoxWalker::with_order(point![1, -1], point![3, 3], WalkOrder::ColumnMajor);
+            let mut iter = walker.iter_morton().unwrap();
+
+            assert_eq!(iter.size_hint(), (15, Some(15)));
+            assert_eq!(
+                iter.by_ref().take(6).collect::<Vec<_>>(),
+                vec![point![1, -1], point![1, 0], point![2, -1], point![2, 0], point![1, 1], point![1, 2]]
+            );
+            assert_eq!(iter.size_hint(), (9, Some(9)));
+            assert_eq!(iter.last(), Some(point![3, 3]));
+        }
+
+        #[test]
+        fn test_morton_empty() {
+            let walker = BBoxWalker::new(point![0, 2], point![2, 0]);
+
+            assert_eq!(walker.iter_morton().unwrap().next(), None);
+            assert_eq!(walker.nth_morton_point(0), None);
+        }
+
+        #[test]
+        fn test_same_points() {
+            let first = point![-1, 0, 2];
+            let last = point![2, 4, 3];
+            let mut expected = BBoxWalker::new(first, last).iter().collect::<Vec<_>>();
+            expected.sort_by_key(|pt| (pt.x, pt.y, pt.z));
+
+            let walker = BBoxWalker::with_order(first, last, WalkOrder::ColumnMajor);
+            let mut points = walker.iter().collect::<Vec<_>>();
+
+            for (n, pt) in points.iter().enumerate() {
+                assert_eq!(walker.nth_point(n), Some(*pt));
+                assert_eq!(walker.position_of(pt), Some(n));
+            }
+
+            points.sort_by_key(|pt| (pt.x, pt.y, pt.z));
+            assert_eq!(points, expected);
+
+            let mut points = walker.iter_morton().unwrap().collect::<Vec<_>>();
+
+            for (n, pt) in points.iter().enumerate() {
+                assert_eq!(walker.nth_morton_point(n), Some(*pt));
+                assert_eq!(walker.morton_position_of(pt), Some(n));
+            }
+
+            points.sort_by_key(|pt| (pt.x, pt.y, pt.z));
+            assert_eq!(points, expected);
+        }
+    }
+
+    #[test]
+    fn test_empty_walker() {
+        let walker = BBoxWalker::new(point![0, 2], point![2, 0]);
+
+        assert!(walker.is_empty());
+        assert!(walker.is_range_empty());
+        assert_eq!(walker.iter().next(), None);
+        assert_eq!(walker.into_iter().next(), None);
+        assert!(!walker.holds(&point![1, 1]));
+    }
+
+    #[test]
+    fn test_single_point_walker() {
+        let walker = BBoxWalker::new(point![1, 2], point![1, 2]);
+
+        assert!(!walker.is_empty());
+        assert_eq!(walker.iter().collect::<Vec<_>>(), vec![point![1, 2]]);
+    }
+
+    #[test]
+    fn test_holds_matches_bbox() {
+        let walker = BBoxWalker::new(point![0, -1], point![2, 3]);
+        let bbox = BBox::from(point![0, -1]..=point![2, 3]);
+
+        for pt in BBoxWalker::new(point![-2, -3], point![4, 5]).iter() {
+            assert_eq!(walker.holds(&pt), bbox.holds(&pt), "{pt}");
+        }
+    }
+
+    #[test]
+    fn test_eq() {
+        assert_eq!(BBoxWalker::new(point![0, 0], point![2, 2]), BBoxWalker::new(point![0, 0], point![2, 2]));
+        assert_ne!(BBoxWalker::new(point![0, 0], point![2, 2]), BBoxWalker::new(point![0, 0], point![2, 3]));
+    }
+
+    #[test]
+    fn test_point_count() {
+        assert_eq!(BBoxWalker::new(point![0, 0, 0], point![1, 2, 3]).point_count(), Some(24));
+        assert_eq!(BBoxWalker::new(point![0, 2], point![2, 0]).point_count(), Some(0));
+        assert_eq!(BBoxWalker::new(point![0, u64::MAX], point![u64::MAX, 0]).point_count(), Some(0));
+    }
+
+    #[test]
+    fn test_point_count_at_numeric_limits() {
+        assert_eq!(BBoxWalker::new(point![-128i8, 0], point![127i8, 1]).point_count(), Some(512));
+        assert_eq!(BBoxWalker::new(point![0u8], point![255u8]).point_count(), Some(256));
+        assert_eq!(BBoxWalker::new(point![i64::MIN], point![i64::MAX]).point_count(), None);
+        assert_eq!(BBoxWalker::new(point![0u64, 0], point![u64::MAX, 1]).point_count(), None);
+        assert_eq!(BBoxWalker::new(point![0u64, 0], point![1 << 32, 1 << 32]).point_count(), None);
+    }
+
+    #[test]
+    fn test_full_range_walk_in_all_orders() {
+        use alloc::collections::BTreeSet;
+
+        for order in [WalkOrder::RowMajor, WalkOrder::ColumnMajor] {
+            let walker = BBoxWalker::with_order(point![i8::MIN, i8::MIN], point![i8::MAX, i8::MAX], order);
+            let points: BTreeSet<_> = walker.iter().map(|pt| (pt.x, pt.y)).collect();
+
+            assert_eq!(walker.point_count(), Some(65536), "{order:?}");
+            assert_eq!(points.len(), 65536, "{order:?}");
+            assert_eq!(walker.nth_point(65535).map(|pt| walker.position_of(&pt)), Some(Some(65535)), "{order:?}");
+            assert_eq!(walker.nth_point(65536), None, "{order:?}");
+
+            let walker = BBoxWalker::with_order(point![u8::MIN, 0], point![u8::MAX, 1], order);
+            let points: BTreeSet<_> = walker.iter().map(|pt| (pt.x, pt.y)).collect();
+
+            assert_eq!(walker.point_count(), Some(512), "{order:?}");
+            assert_eq!(points.len(), 512, "{order:?}");
+            assert!(walker.iter().all(|pt| walker.nth_point(walker.position_of(&pt).unwrap()) == Some(pt)), "{order:?}");
+        }
+
+        let walker = BBoxWalker::new(point![i8::MIN, i8::MIN], point![i8::MAX, i8::MAX]);
+        let points: BTreeSet<_> = walker.iter_morton().unwrap().map(|pt| (pt.x, pt.y)).collect();
+
+        assert_eq!(points.len(), 65536);
+        assert_eq!(walker.nth_morton_point(65535), Some(point![i8::MAX, i8::MAX]));
+        assert_eq!(walker.morton_position_of(&point![i8::MAX, i8::MAX]), Some(65535));
+
+        let walker = BBoxWalker::new(point![u8::MIN, 0], point![u8::MAX, 1]);
+
+        assert_eq!(walker.iter_morton().unwrap().count(), 512);
+        assert!(walker.iter_morton().unwrap().enumerate().all(|(n, pt)| walker.morton_position_of(&pt) == Some(n)));
+    }
+
+    #[test]
+    fn test_morton_overflowing_count() {
+        let walker = BBoxWalker::new(point![0u64, 0], point![u64::MAX, u64::MAX]);
+
+        assert_eq!(walker.point_count(), None);
+        assert!(walker.iter_morton().is_none());
+        assert_eq!(walker.nth_morton_point(0), None);
+        assert_eq!(walker.morton_position_of(&point![0, 0]), None);
+    }
+
+    #[test]
+    fn test_nth_point_at_numeric_limits() {
+        let walker = BBoxWalker::new(point![-128i8, 0], point![127i8, 1]);
+
+        assert_eq!(walker.nth_point(0), Some(point![-128, 0]));
+        assert_eq!(walker.nth_point(511), Some(point![127, 1]));
+        assert_eq!(walker.nth_point(512), None);
+        assert_eq!(walker.position_of(&point![127, 1]), Some(511));
+        assert_eq!(walker.position_of(&point![0, 1]), Some(257));
+
+        let walker = BBoxWalker::new(point![u128::MAX - 1], point![u128::MAX]);
+
+        assert_eq!(walker.nth_point(1), Some(point![u128::MAX]));
+        assert_eq!(walker.position_of(&point![u128::MAX]), Some(1));
+    }
+
+    #[test]
+    fn test_nth_point() {
+        let walker = BBoxWalker::new(point![0, 0, 0], point![1, 2, 3]);
+
+        for (n, pt) in walker.iter().enumerate() {
+            assert_eq!(walker.nth_point(n), Some(pt));
+            assert_eq!(walker.position_of(&pt), Some(n));
+        }
+
+        assert_eq!(walker.nth_point(24), None);
+    }
+
+    #[test]
+    fn test_position_of_outside_point() {
+        let walker = BBoxWalker::new(point![0, 0, 0], point![1, 2, 3]);
+
+        assert_eq!(walker.position_of(&point![-1, 0, 0]), None);
+        assert_eq!(walker.position_of(&point![0, 3, 0]), None);
+    }
+
+    #[test]
+    fn test_walk_by_chunks() {
+        let walker = BBoxWalker::new(point![-1, 0, 2], point![1, 2, 4]);
+        let chunk_size = walker.point_count().unwrap().div_ceil(4);
+
+        let points: Vec<_> = (0..4)
+            .flat_map(|chunk| {
+                core::iter::successors(walker.nth_point(chunk * chunk_size), |pt| walker.next(pt))
+                    .take(chunk_size)
+            })
+            .collect();
+
+        assert_eq!(points, walker.iter().collect::<Vec<_>>());
+    }
+
     #[test]
     fn test_below_left_point() {
         let walker = BBoxWalker::new(point![0, 0], point![2, 2]);
@@ -177,4 +990,66 @@ mod tests {
 
         assert_eq!(walker.next(&point![3, 3]), None);
     }
-}
\ No newline at end of file
+
+    mod boundary {
+        use na::point;
+        use super::*;
+
+        fn sorted(mut points: Vec<Point<i32, 3>>) -> Vec<Point<i32, 3>> {
+            points.sort_by_key(|pt| (pt.x, pt.y, pt.z));
+            points
+        }
+
+        #[test]
+        fn test_boundary_counts() {
+            assert_eq!(BBoxWalker::new(point![0, 0], point![3, 3]).iter_boundary().count(), 12);
+            assert_eq!(BBoxWalker::new(point![0, 0, 0], point![2, 2, 2]).iter_boundary().count(), 26);
+            assert_eq!(BBoxWalker::new(point![0], point![5]).iter_boundary().collect::<Vec<_>>(), vec![point![0], point![5]]);
+            assert_eq!(BBoxWalker::new(point![0, 2], point![2, 0]).iter_boundary().count(), 0);
+        }
+
+        #[test]
+        fn test_boundary_matches_filter() {
+            let walker = BBoxWalker::with_order(point![-1, 0, 2], point![3, 4, 5], WalkOrder::ColumnMajor);
+            let (first, last) = (*walker.first(), *walker.last());
+
+            let expected: Vec<_> = BBoxWalker::new(first, last).iter()
+                .filter(|pt| (0..3).any(|idx| pt[idx] == first[idx] || pt[idx] == last[idx]))
+                .collect();
+
+            assert_eq!(walker.iter_boundary().collect::<Vec<_>>(), expected);
+        }
+
+        #[test]
+        fn test_thin_boundary() {
+            for walker in [BBoxWalker::new(point![0, 0], point![0, 4]), BBoxWalker::new(point![0, 0], point![4, 0])] {
+                assert_eq!(walker.iter_boundary().collect::<Vec<_>>(), walker.iter().collect::<Vec<_>>());
+            }
+        }
+
+        #[test]
+        fn test_faces() {
+            let walker = BBoxWalker::new(point![0, 0, 0], point![3, 2, 4]);
+
+            assert_eq!(walker.iter_face(0, false).count(), 15);
+            assert_eq!(walker.iter_face(0, true).count(), 15);
+            assert_eq!(walker.iter_face(1, false).count(), 20);
+            assert_eq!(walker.iter_face(2, true).count(), 12);
+            assert!(walker.iter_face(0, true).all(|pt| pt.x == 3));
+
+            let mut faces: Vec<_> = (0..3)
+                .flat_map(|axis| walker.iter_face(axis, false).chain(walker.iter_face(axis, true)))
+                .collect();
+
+            faces = sorted(faces);
+            faces.dedup();
+
+            assert_eq!(faces, sorted(walker.iter_boundary().collect()));
+        }
+
+        #[test]
+        fn test_empty_faces() {
+            assert_eq!(BBoxWalker::new(point![0, 2], point![2, 0]).iter_face(1, true).count(), 0);
+        }
+    }
+}
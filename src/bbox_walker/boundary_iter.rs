@@ -0,0 +1,49 @@
+use core::ops::AddAssign;
+use na::{Point, Scalar};
+use num_traits::One;
+use crate::BBoxWalker;
+
+/// Iterator over the boundary points of a walker, in row major order, see [`BBoxWalker::iter_boundary`]
+pub struct BoundaryIter<N: Scalar, const D: usize> {
+    next: Option<Point<N, D>>,
+    walker: BBoxWalker<N, D>,
+}
+
+impl<N: Scalar, const D: usize> BoundaryIter<N, D> {
+    pub fn new(walker: &BBoxWalker<N, D>) -> BoundaryIter<N, D>
+    where
+        N: Copy + PartialOrd
+    {
+        BoundaryIter {
+            next: if walker.is_empty() { None } else { Some(*walker.first()) },
+            walker: BBoxWalker::new(*walker.first(), *walker.last()),
+        }
+    }
+}
+
+impl<N: AddAssign + Copy + One + Ord + Scalar, const D: usize> Iterator for BoundaryIter<N, D> {
+    type Item = Point<N, D>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+        let first = self.walker.first();
+        let last = self.walker.last();
+
+        // Rows (all axes but the fastest one) on the shell are walked entirely,
+        // inner rows are only made of their first and last points.
+        let on_shell = (0..D.saturating_sub(1))
+            .any(|idx| current[idx] == first[idx] || current[idx] == last[idx]);
+
+        self.next = match D.checked_sub(1) {
+            Some(fast) if !on_shell && current[fast] == first[fast] && first[fast] < last[fast] => {
+                let mut next = current;
+                next[fast] = last[fast];
+
+                Some(next)
+            }
+            _ => self.walker.next(&current),
+        };
+
+        Some(current)
+    }
+}
@@ -1,20 +1,38 @@
-use std::ops::AddAssign;
+use core::ops::AddAssign;
 use na::{Point, Scalar};
 use num_traits::One;
 use crate::BBoxWalker;
 
+/// Iterator over the points of a walker
 pub struct Iter<'a, N: Scalar, const D: usize> {
+    first: Option<Point<N, D>>,
     last: Option<Point<N, D>>,
     walker: &'a BBoxWalker<N, D>
 }
 
 impl<'a, N: Scalar, const D: usize> Iter<'a, N, D> {
-    pub fn new(walker: &'a BBoxWalker<N, D>) -> Iter<'a, N, D> {
+    pub fn new(walker: &'a BBoxWalker<N, D>) -> Iter<'a, N, D>
+    where
+        N: Copy
+    {
+        Iter::starting_at(walker, Some(*walker.first()))
+    }
+
+    /// Builds an iterator yielding first, then the points coming after it
+    pub(crate) fn starting_at(walker: &'a BBoxWalker<N, D>, first: Option<Point<N, D>>) -> Iter<'a, N, D> {
         Iter {
+            first,
             last: None,
             walker,
         }
     }
+
+    /// Last yielded point, None if no point has been yielded yet.
+    /// Can be given to [`BBoxWalker::iter_from`] to resume the walk, after this point.
+    #[inline]
+    pub fn current(&self) -> Option<&Point<N, D>> {
+        self.last.as_ref()
+    }
 }
 
 impl<'a, N: AddAssign + Copy + One + Ord + Scalar, const D: usize> Iterator for Iter<'a, N, D> {
@@ -29,9 +47,11 @@ impl<'a, N: AddAssign + Copy + One + Ord + Scalar, const D: usize> Iterator for
             }
 
             next
+        } else if self.walker.is_empty() {
+            None
         } else {
-            self.last = Some(*self.walker.first());
+            self.last = Some(self.first.take()?);
             self.last
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,82 @@
+use na::{Point, Scalar};
+use num_traits::NumCast;
+use crate::BBoxWalker;
+use super::{add_offset, morton_bits};
+
+/// Iterator over the points of a walker in Morton order, see [`BBoxWalker::iter_morton`].
+/// Keeps the offsets of the current point, moving to the next morton code at each step.
+pub struct MortonIter<'a, N: Scalar, const D: usize> {
+    walker: &'a BBoxWalker<N, D>,
+    sizes: [usize; D],
+    bits: [u32; D],
+    offsets: Option<[usize; D]>,
+    remaining: usize,
+}
+
+impl<'a, N: Scalar, const D: usize> MortonIter<'a, N, D> {
+    /// Builds an iterator over walker points in Morton order, None if the point count does not fit in an usize
+    pub fn new(walker: &'a BBoxWalker<N, D>) -> Option<MortonIter<'a, N, D>>
+    where
+        N: Copy + NumCast + Ord
+    {
+        let remaining = walker.point_count()?;
+        let (sizes, bits) = walker.morton_layout().unwrap_or(([0; D], [0; D]));
+
+        Some(MortonIter {
+            walker,
+            sizes,
+            bits,
+            offsets: if remaining > 0 { Some([0; D]) } else { None },
+            remaining,
+        })
+    }
+
+    /// Moves offsets to the next morton code, returns false if there is none
+    fn increment(&self, offsets: &mut [usize; D]) -> bool {
+        // Adds one to the code: clears trailing ones, then sets the first zero bit
+        for (idx, level) in morton_bits(&self.bits).rev() {
+            if (offsets[idx] >> level) & 1 == 1 {
+                offsets[idx] &= !(1 << level);
+            } else {
+                offsets[idx] |= 1 << level;
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+impl<N: Copy + NumCast + Ord + Scalar, const D: usize> Iterator for MortonIter<'_, N, D> {
+    type Item = Point<N, D>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.offsets?;
+        let mut point = *self.walker.first();
+
+        for (idx, offset) in current.iter().enumerate() {
+            let x = unsafe { point.get_unchecked_mut(idx) };
+            *x = add_offset(*x, *offset)?;
+        }
+
+        // Codes of the padding added to reach powers of two are skipped
+        let mut offsets = current;
+        let mut valid = self.increment(&mut offsets);
+
+        while valid && offsets.iter().zip(self.sizes.iter()).any(|(offset, size)| offset >= size) {
+            valid = self.increment(&mut offsets);
+        }
+
+        self.offsets = if valid { Some(offsets) } else { None };
+        self.remaining -= 1;
+
+        Some(point)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<N: Copy + NumCast + Ord + Scalar, const D: usize> ExactSizeIterator for MortonIter<'_, N, D> {}
@@ -0,0 +1,172 @@
+use core::ops::Range;
+use na::{Point, Scalar};
+use num_traits::NumCast;
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+use crate::BBoxWalker;
+
+/// Parallel iterator on walked points.
+/// Splits are done by index, so each thread walks a contiguous part of the xy order.
+pub struct ParIter<'a, N: Scalar, const D: usize> {
+    walker: &'a BBoxWalker<N, D>,
+    len: usize,
+}
+
+impl<'a, N: Scalar, const D: usize> ParIter<'a, N, D> {
+    /// Builds a parallel iterator on walker points, None if the point count does not fit in an usize
+    pub fn new(walker: &'a BBoxWalker<N, D>) -> Option<ParIter<'a, N, D>>
+    where
+        N: Copy + NumCast + Ord
+    {
+        Some(ParIter { walker, len: walker.point_count()? })
+    }
+}
+
+impl<N, const D: usize> ParallelIterator for ParIter<'_, N, D>
+where
+    N: Copy + NumCast + Ord + Scalar + Send + Sync,
+{
+    type Item = Point<N, D>;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    #[inline]
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.len)
+    }
+}
+
+impl<N, const D: usize> IndexedParallelIterator for ParIter<'_, N, D>
+where
+    N: Copy + NumCast + Ord + Scalar + Send + Sync,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(IndexIter {
+            walker: self.walker,
+            indexes: 0..self.len,
+        })
+    }
+}
+
+/// Iterates over walked points by index
+struct IndexIter<'a, N: Scalar, const D: usize> {
+    walker: &'a BBoxWalker<N, D>,
+    indexes: Range<usize>,
+}
+
+impl<N, const D: usize> Iterator for IndexIter<'_, N, D>
+where
+    N: Copy + NumCast + Ord + Scalar,
+{
+    type Item = Point<N, D>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.walker.nth_point(self.indexes.next()?)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indexes.size_hint()
+    }
+}
+
+impl<N, const D: usize> DoubleEndedIterator for IndexIter<'_, N, D>
+where
+    N: Copy + NumCast + Ord + Scalar,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.walker.nth_point(self.indexes.next_back()?)
+    }
+}
+
+impl<N, const D: usize> ExactSizeIterator for IndexIter<'_, N, D>
+where
+    N: Copy + NumCast + Ord + Scalar,
+{}
+
+impl<'a, N, const D: usize> Producer for IndexIter<'a, N, D>
+where
+    N: Copy + NumCast + Ord + Scalar + Send + Sync,
+{
+    type Item = Point<N, D>;
+    type IntoIter = IndexIter<'a, N, D>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let mid = self.indexes.start + index;
+
+        (
+            IndexIter { walker: self.walker, indexes: self.indexes.start..mid },
+            IndexIter { walker: self.walker, indexes: mid..self.indexes.end },
+        )
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use na::point;
+    use super::*;
+
+    #[test]
+    fn test_collect() {
+        let walker = BBoxWalker::new(point![0, -2, 1], point![7, 5, 9]);
+
+        assert_eq!(
+            walker.par_iter().unwrap().collect::<Vec<_>>(),
+            walker.iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_count() {
+        for last in [point![0, 0, 0], point![3, 1, 4], point![10, 20, 30]] {
+            let walker = BBoxWalker::new(point![0, 0, 0], last);
+
+            assert_eq!(walker.par_iter().unwrap().count(), walker.iter().count());
+        }
+    }
+
+    #[test]
+    fn test_overflowing_count() {
+        let walker = BBoxWalker::new(point![i64::MIN, 0], point![i64::MAX, 1]);
+
+        assert!(walker.par_iter().is_none());
+    }
+
+    mod properties {
+        use na::Point2;
+        use proptest::prelude::*;
+        use crate::{Holds, Walkable};
+        use crate::bbox::arbitrary::{bbox_strategy, BBoxParams};
+        use super::*;
+
+        proptest! {
+            #[test]
+            fn count_matches_lattice(bbox in bbox_strategy::<i32, _, 2>(-8..8, BBoxParams { allow_unbounded: false, allow_empty: true })) {
+                let lattice = (-10..10)
+                    .flat_map(|x| (-10..10).map(move |y| Point2::new(x, y)))
+                    .filter(|pt| bbox.holds(pt))
+                    .count();
+
+                let walker = bbox.walk().unwrap();
+
+                prop_assert_eq!(walker.par_iter().unwrap().count(), lattice);
+            }
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use core::ops::AddAssign;
+use na::{Point, Scalar};
+use num_traits::One;
+use crate::BBoxWalker;
+
+/// Owning iterator over the points of a walker
+pub struct WalkIter<N: Scalar, const D: usize> {
+    last: Option<Point<N, D>>,
+    walker: BBoxWalker<N, D>,
+}
+
+impl<N: Scalar, const D: usize> WalkIter<N, D> {
+    pub fn new(walker: BBoxWalker<N, D>) -> WalkIter<N, D> {
+        WalkIter {
+            last: None,
+            walker,
+        }
+    }
+
+    /// Walker used by this iterator
+    #[inline]
+    pub fn walker(&self) -> &BBoxWalker<N, D> {
+        &self.walker
+    }
+}
+
+impl<N: AddAssign + Copy + One + Ord + Scalar, const D: usize> Iterator for WalkIter<N, D> {
+    type Item = Point<N, D>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(last) = &self.last {
+            let next = self.walker.next(last);
+
+            if next.is_some() {
+                self.last = next;
+            }
+
+            next
+        } else if self.walker.is_empty() {
+            None
+        } else {
+            self.last = Some(*self.walker.first());
+            self.last
+        }
+    }
+}
@@ -0,0 +1,10 @@
+/// Order in which a walker goes through its points
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum WalkOrder {
+    /// xy order: first axis changes the slowest, last axis the fastest
+    #[default]
+    RowMajor,
+
+    /// yx order: last axis changes the slowest, first axis the fastest
+    ColumnMajor,
+}
@@ -1,10 +1,22 @@
+#![cfg_attr(all(not(feature = "std"), not(test)), no_std)]
+
+extern crate alloc;
 extern crate nalgebra as na;
 
 pub mod bbox;
 pub mod bbox_walker;
+pub mod metrics;
+pub mod neighborhood;
+pub mod predicates;
+pub mod prelude;
+pub mod raster;
+pub mod segment;
 pub mod traits;
 pub mod wasm;
 
-pub use bbox::BBox;
+pub use bbox::{AxisRange, BBox, BBoxBuilder, PeriodicBBox};
+#[cfg(feature = "rand")]
+pub use bbox::UniformBBox;
 pub use bbox_walker::BBoxWalker;
-pub use traits::{Holds, Intersection, IsRangeEmpty, Overlaps, PointBounds, Walkable};
+pub use segment::Segment;
+pub use traits::{DimBounds, Holds, Intersection, IntoBBox, IntoPoint, IsRangeEmpty, Overlaps, PointBounds, PointPredicate, Walkable};
@@ -0,0 +1,65 @@
+use na::{ClosedAdd, ClosedSub, Point, Scalar};
+use num_traits::Zero;
+
+/// Computes the manhattan (L1) distance between two points
+///
+/// # Example
+/// ```
+/// use nalgebra::point;
+/// use pythagore::metrics::manhattan_distance;
+///
+/// assert_eq!(manhattan_distance(&point![1, 2], &point![4, -2]), 7);
+/// ```
+pub fn manhattan_distance<N, const D: usize>(a: &Point<N, D>, b: &Point<N, D>) -> N
+where
+    N: ClosedAdd + ClosedSub + Copy + Ord + Scalar + Zero
+{
+    a.iter().zip(b.iter())
+        .fold(N::zero(), |acc, (a, b)| acc + abs_diff(*a, *b))
+}
+
+/// Computes the chebyshev (L∞) distance between two points
+///
+/// # Example
+/// ```
+/// use nalgebra::point;
+/// use pythagore::metrics::chebyshev_distance;
+///
+/// assert_eq!(chebyshev_distance(&point![1, 2], &point![4, -2]), 4);
+/// ```
+pub fn chebyshev_distance<N, const D: usize>(a: &Point<N, D>, b: &Point<N, D>) -> N
+where
+    N: ClosedSub + Copy + Ord + Scalar + Zero
+{
+    a.iter().zip(b.iter())
+        .fold(N::zero(), |acc, (a, b)| acc.max(abs_diff(*a, *b)))
+}
+
+/// Computes the absolute difference between two values, without requiring a signed type
+#[inline]
+pub(crate) fn abs_diff<N: ClosedSub + Ord>(a: N, b: N) -> N {
+    if a > b { a - b } else { b - a }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use na::point;
+    use super::*;
+
+    #[test]
+    fn test_manhattan_distance() {
+        assert_eq!(manhattan_distance(&point![0, 0], &point![0, 0]), 0);
+        assert_eq!(manhattan_distance(&point![-1, -2], &point![3, 1]), 7);
+        assert_eq!(manhattan_distance(&point![3, 1], &point![-1, -2]), 7);
+        assert_eq!(manhattan_distance(&point![1u32, 5, 2], &point![4, 1, 2]), 7);
+    }
+
+    #[test]
+    fn test_chebyshev_distance() {
+        assert_eq!(chebyshev_distance(&point![0, 0], &point![0, 0]), 0);
+        assert_eq!(chebyshev_distance(&point![-1, -2], &point![3, 1]), 4);
+        assert_eq!(chebyshev_distance(&point![3, 1], &point![-1, -2]), 4);
+        assert_eq!(chebyshev_distance(&point![1u32, 5, 2], &point![4, 1, 2]), 4);
+    }
+}
@@ -0,0 +1,281 @@
+use core::iter::FusedIterator;
+use na::{Point, Scalar};
+use num_traits::{CheckedAdd, CheckedSub, One};
+
+use crate::{BBox, Holds};
+
+/// Returns the up to 3^D - 1 lattice points surrounding the given point (Moore neighborhood) held by bbox.
+/// Neighbors are yielded in xy order. Neighbors beyond the scalar type limits are skipped.
+///
+/// # Example
+/// ```
+/// use nalgebra::point;
+/// use pythagore::BBox;
+/// use pythagore::neighborhood::moore_neighbors;
+///
+/// let bbox = BBox::from(point![0, 0]..point![5, 5]);
+///
+/// assert_eq!(moore_neighbors(&point![2, 2], &bbox).count(), 8);
+/// assert_eq!(moore_neighbors(&point![0, 0], &bbox).count(), 3);
+/// ```
+pub fn moore_neighbors<'a, N, const D: usize>(point: &Point<N, D>, bbox: &'a BBox<N, D>) -> MooreNeighbors<'a, N, D>
+where
+    N: CheckedAdd + CheckedSub + Copy + One + PartialOrd + Scalar
+{
+    MooreNeighbors {
+        bbox,
+        center: *point,
+        index: 0,
+    }
+}
+
+/// Returns the up to 2·D axis neighbors of the given point (von Neumann neighborhood) held by bbox.
+/// Neighbors are yielded axis by axis, the lower one first. Neighbors beyond the scalar type limits
+/// are skipped.
+///
+/// # Example
+/// ```
+/// use nalgebra::point;
+/// use pythagore::BBox;
+/// use pythagore::neighborhood::von_neumann_neighbors;
+///
+/// let bbox = BBox::from(point![0, 0]..point![5, 5]);
+///
+/// assert_eq!(
+///     von_neumann_neighbors(&point![0, 2], &bbox).collect::<Vec<_>>(),
+///     vec![point![1, 2], point![0, 1], point![0, 3]]
+/// );
+/// ```
+pub fn von_neumann_neighbors<'a, N, const D: usize>(point: &Point<N, D>, bbox: &'a BBox<N, D>) -> VonNeumannNeighbors<'a, N, D>
+where
+    N: CheckedAdd + CheckedSub + Copy + One + PartialOrd + Scalar
+{
+    VonNeumannNeighbors {
+        bbox,
+        center: *point,
+        index: 0,
+    }
+}
+
+/// Iterator over the Moore neighbors of a point, see [`moore_neighbors`]
+#[derive(Clone, Debug)]
+pub struct MooreNeighbors<'a, N: Scalar, const D: usize> {
+    bbox: &'a BBox<N, D>,
+    center: Point<N, D>,
+    index: usize,
+}
+
+impl<'a, N, const D: usize> Iterator for MooreNeighbors<'a, N, D>
+where
+    N: CheckedAdd + CheckedSub + Copy + One + PartialOrd + Scalar
+{
+    type Item = Point<N, D>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let count = 3usize.pow(D as u32);
+
+        'neighbors: while self.index < count {
+            let index = self.index;
+            self.index += 1;
+
+            // Center is the middle index, where all offsets are zero
+            if index == count / 2 {
+                continue;
+            }
+
+            let mut point = self.center;
+            let mut rem = index;
+
+            for idx in (0..D).rev() {
+                let x = unsafe { point.get_unchecked_mut(idx) };
+                let moved = match rem % 3 {
+                    0 => x.checked_sub(&N::one()),
+                    2 => x.checked_add(&N::one()),
+                    _ => Some(*x),
+                };
+
+                match moved {
+                    Some(moved) => *x = moved,
+                    None => continue 'neighbors,
+                }
+
+                rem /= 3;
+            }
+
+            if self.bbox.holds(&point) {
+                return Some(point);
+            }
+        }
+
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(3usize.pow(D as u32).saturating_sub(self.index)))
+    }
+}
+
+impl<'a, N, const D: usize> FusedIterator for MooreNeighbors<'a, N, D>
+where
+    N: CheckedAdd + CheckedSub + Copy + One + PartialOrd + Scalar
+{}
+
+/// Iterator over the von Neumann neighbors of a point, see [`von_neumann_neighbors`]
+#[derive(Clone, Debug)]
+pub struct VonNeumannNeighbors<'a, N: Scalar, const D: usize> {
+    bbox: &'a BBox<N, D>,
+    center: Point<N, D>,
+    index: usize,
+}
+
+impl<'a, N, const D: usize> Iterator for VonNeumannNeighbors<'a, N, D>
+where
+    N: CheckedAdd + CheckedSub + Copy + One + PartialOrd + Scalar
+{
+    type Item = Point<N, D>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < 2 * D {
+            let idx = self.index / 2;
+            let mut point = self.center;
+            let x = unsafe { point.get_unchecked_mut(idx) };
+
+            let moved = if self.index.is_multiple_of(2) {
+                x.checked_sub(&N::one())
+            } else {
+                x.checked_add(&N::one())
+            };
+
+            self.index += 1;
+
+            if let Some(moved) = moved {
+                *x = moved;
+
+                if self.bbox.holds(&point) {
+                    return Some(point);
+                }
+            }
+        }
+
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some((2 * D).saturating_sub(self.index)))
+    }
+}
+
+impl<'a, N, const D: usize> FusedIterator for VonNeumannNeighbors<'a, N, D>
+where
+    N: CheckedAdd + CheckedSub + Copy + One + PartialOrd + Scalar
+{}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use na::point;
+    use super::*;
+
+    mod moore_neighbors {
+        use super::*;
+
+        #[test]
+        fn test_interior_point() {
+            let bbox = BBox::from(point![0, 0]..point![5, 5]);
+
+            assert_eq!(
+                moore_neighbors(&point![2, 2], &bbox).collect::<Vec<_>>(),
+                vec![
+                    point![1, 1], point![1, 2], point![1, 3],
+                    point![2, 1], point![2, 3],
+                    point![3, 1], point![3, 2], point![3, 3],
+                ]
+            );
+        }
+
+        #[test]
+        fn test_corner_point() {
+            let bbox = BBox::from(point![0, 0]..point![5, 5]);
+
+            assert_eq!(
+                moore_neighbors(&point![4, 4], &bbox).collect::<Vec<_>>(),
+                vec![point![3, 3], point![3, 4], point![4, 3]]
+            );
+        }
+
+        #[test]
+        fn test_outside_point() {
+            let bbox = BBox::from(point![0, 0]..point![5, 5]);
+
+            assert_eq!(moore_neighbors(&point![-1, -1], &bbox).collect::<Vec<_>>(), vec![point![0, 0]]);
+            assert_eq!(moore_neighbors(&point![-1, 2], &bbox).count(), 3);
+            assert_eq!(moore_neighbors(&point![-2, 2], &bbox).count(), 0);
+        }
+
+        #[test]
+        fn test_unsigned_corner_point() {
+            let bbox = BBox::from(point![0u32, 0]..point![5, 5]);
+
+            assert_eq!(
+                moore_neighbors(&point![0, 0], &bbox).collect::<Vec<_>>(),
+                vec![point![0, 1], point![1, 0], point![1, 1]]
+            );
+            assert_eq!(moore_neighbors(&point![usize::MAX, 0], &BBox::from(..)).count(), 3);
+        }
+
+        #[test]
+        fn test_3d_interior_point() {
+            let bbox = BBox::from(point![0, 0, 0]..point![5, 5, 5]);
+
+            assert_eq!(moore_neighbors(&point![2, 2, 2], &bbox).count(), 26);
+        }
+    }
+
+    mod von_neumann_neighbors {
+        use super::*;
+
+        #[test]
+        fn test_interior_point() {
+            let bbox = BBox::from(point![0, 0]..point![5, 5]);
+
+            assert_eq!(
+                von_neumann_neighbors(&point![2, 2], &bbox).collect::<Vec<_>>(),
+                vec![point![1, 2], point![3, 2], point![2, 1], point![2, 3]]
+            );
+        }
+
+        #[test]
+        fn test_corner_point() {
+            let bbox = BBox::from(point![0, 0]..point![5, 5]);
+
+            assert_eq!(
+                von_neumann_neighbors(&point![0, 0], &bbox).collect::<Vec<_>>(),
+                vec![point![1, 0], point![0, 1]]
+            );
+        }
+
+        #[test]
+        fn test_unsigned_corner_point() {
+            let bbox = BBox::from(point![0u32, 0]..point![5, 5]);
+
+            assert_eq!(
+                von_neumann_neighbors(&point![0, 0], &bbox).collect::<Vec<_>>(),
+                vec![point![1, 0], point![0, 1]]
+            );
+            assert_eq!(
+                von_neumann_neighbors(&point![usize::MAX, 0], &BBox::from(..)).collect::<Vec<_>>(),
+                vec![point![usize::MAX - 1, 0], point![usize::MAX, 1]]
+            );
+        }
+
+        #[test]
+        fn test_outside_point() {
+            let bbox = BBox::from(point![0, 0]..point![5, 5]);
+
+            assert_eq!(von_neumann_neighbors(&point![-1, 2], &bbox).collect::<Vec<_>>(), vec![point![0, 2]]);
+            assert_eq!(von_neumann_neighbors(&point![-1, -1], &bbox).count(), 0);
+        }
+    }
+}
@@ -0,0 +1,245 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use na::{ClosedAdd, ClosedDiv, ClosedMul, ClosedSub, Point, Point2, Scalar};
+use num_traits::{One, Zero};
+
+use crate::PointPredicate;
+
+/// Computes the orientation of the c point against the line going from a to b, as twice the signed
+/// area of the abc triangle: positive if abc is counter-clockwise, negative if clockwise and zero if
+/// points are collinear. Exact on (signed) integer scalars.
+///
+/// # Example
+/// ```
+/// use nalgebra::point;
+/// use pythagore::predicates::orient2d;
+///
+/// assert_eq!(orient2d(&point![0, 0], &point![2, 0], &point![0, 2]), 4);
+/// assert_eq!(orient2d(&point![0, 0], &point![0, 2], &point![2, 0]), -4);
+/// ```
+pub fn orient2d<N>(a: &Point2<N>, b: &Point2<N>, c: &Point2<N>) -> N
+where
+    N: ClosedMul + ClosedSub + Copy + Scalar
+{
+    (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)
+}
+
+/// Tests if p is inside the abc triangle, whatever its orientation. Points on the triangle edges are
+/// inside, degenerate (flat) triangles hold no point.
+///
+/// # Example
+/// ```
+/// use nalgebra::point;
+/// use pythagore::predicates::point_in_triangle;
+///
+/// assert!(point_in_triangle(&point![1, 1], &point![0, 0], &point![4, 0], &point![0, 4]));
+/// assert!(!point_in_triangle(&point![3, 3], &point![0, 0], &point![4, 0], &point![0, 4]));
+/// ```
+pub fn point_in_triangle<N>(p: &Point2<N>, a: &Point2<N>, b: &Point2<N>, c: &Point2<N>) -> bool
+where
+    N: ClosedMul + ClosedSub + Copy + PartialOrd + Scalar + Zero
+{
+    let area = orient2d(a, b, c);
+    let orients = [orient2d(a, b, p), orient2d(b, c, p), orient2d(c, a, p)];
+
+    if area > N::zero() {
+        orients.iter().all(|o| *o >= N::zero())
+    } else if area < N::zero() {
+        orients.iter().all(|o| *o <= N::zero())
+    } else {
+        false
+    }
+}
+
+/// Computes twice the signed area of the given polygon, using the shoelace formula. The result is
+/// positive if points are in counter-clockwise order. Exact on (signed) integer scalars.
+///
+/// # Example
+/// ```
+/// use nalgebra::point;
+/// use pythagore::predicates::polygon_double_area;
+///
+/// assert_eq!(polygon_double_area(&[point![0, 0], point![1, 0], point![0, 1]]), 1);
+/// ```
+pub fn polygon_double_area<N>(points: &[Point2<N>]) -> N
+where
+    N: ClosedAdd + ClosedMul + ClosedSub + Copy + Scalar + Zero
+{
+    points.iter().zip(points.iter().cycle().skip(1))
+        .fold(N::zero(), |acc, (a, b)| acc + a.x * b.y - b.x * a.y)
+}
+
+/// Computes the signed area of the given polygon, see [`polygon_double_area`].
+/// On integer scalars the result is rounded toward zero.
+///
+/// # Example
+/// ```
+/// use nalgebra::point;
+/// use pythagore::predicates::polygon_area;
+///
+/// assert_eq!(polygon_area(&[point![0.0, 0.0], point![1.0, 0.0], point![1.0, 1.0], point![0.0, 1.0]]), 1.0);
+/// ```
+#[inline]
+pub fn polygon_area<N>(points: &[Point2<N>]) -> N
+where
+    N: ClosedAdd + ClosedDiv + ClosedMul + ClosedSub + Copy + One + Scalar + Zero
+{
+    polygon_double_area(points) / (N::one() + N::one())
+}
+
+/// Matches points matching all inner predicates. Matches every point if empty.
+///
+/// # Example
+/// ```
+/// use nalgebra::point;
+/// use pythagore::{BBox, PointPredicate};
+/// use pythagore::predicates::{All, Not};
+///
+/// let ring = All::<i32, 2>(vec![
+///     Box::new(BBox::from(point![0, 0]..point![6, 6])),
+///     Box::new(Not(Box::new(BBox::from(point![2, 2]..point![4, 4])))),
+/// ]);
+///
+/// assert!(ring.test(&point![1, 1]));
+/// assert!(!ring.test(&point![3, 3]));
+/// ```
+pub struct All<N: Scalar, const D: usize>(pub Vec<Box<dyn PointPredicate<N, D>>>);
+
+impl<N: Scalar, const D: usize> PointPredicate<N, D> for All<N, D> {
+    fn test(&self, pt: &Point<N, D>) -> bool {
+        self.0.iter().all(|predicate| predicate.test(pt))
+    }
+}
+
+/// Matches points matching at least one inner predicate. Matches no point if empty.
+///
+/// # Example
+/// ```
+/// use nalgebra::point;
+/// use pythagore::{BBox, PointPredicate};
+/// use pythagore::predicates::Any;
+///
+/// let cross = Any::<i32, 2>(vec![
+///     Box::new(BBox::from(point![0, 2]..point![6, 4])),
+///     Box::new(BBox::from(point![2, 0]..point![4, 6])),
+/// ]);
+///
+/// assert!(cross.test(&point![3, 0]));
+/// assert!(!cross.test(&point![0, 0]));
+/// ```
+pub struct Any<N: Scalar, const D: usize>(pub Vec<Box<dyn PointPredicate<N, D>>>);
+
+impl<N: Scalar, const D: usize> PointPredicate<N, D> for Any<N, D> {
+    fn test(&self, pt: &Point<N, D>) -> bool {
+        self.0.iter().any(|predicate| predicate.test(pt))
+    }
+}
+
+/// Matches points not matching inner predicate
+///
+/// # Example
+/// ```
+/// use nalgebra::point;
+/// use pythagore::{BBox, PointPredicate};
+/// use pythagore::predicates::Not;
+///
+/// let outside = Not::<i32, 2>(Box::new(BBox::from(point![0, 0]..point![5, 5])));
+///
+/// assert!(outside.test(&point![5, 0]));
+/// assert!(!outside.test(&point![0, 0]));
+/// ```
+pub struct Not<N: Scalar, const D: usize>(pub Box<dyn PointPredicate<N, D>>);
+
+impl<N: Scalar, const D: usize> PointPredicate<N, D> for Not<N, D> {
+    #[inline]
+    fn test(&self, pt: &Point<N, D>) -> bool {
+        !self.0.test(pt)
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use na::point;
+    use super::*;
+
+    #[test]
+    fn test_orient2d() {
+        assert!(orient2d(&point![0, 0], &point![1, 0], &point![0, 1]) > 0);
+        assert!(orient2d(&point![0.0, 0.0], &point![0.0, 1.0], &point![1.0, 0.0]) < 0.0);
+        assert_eq!(orient2d(&point![0, 0], &point![1, 1], &point![3, 3]), 0);
+    }
+
+    #[test]
+    fn test_point_in_int_triangle() {
+        let (a, b, c) = (point![0, 0], point![4, 0], point![0, 4]);
+
+        assert!(point_in_triangle(&point![1, 1], &a, &b, &c));
+        assert!(point_in_triangle(&point![1, 1], &a, &c, &b));
+        assert!(point_in_triangle(&point![2, 2], &a, &b, &c));
+        assert!(point_in_triangle(&point![0, 0], &a, &b, &c));
+        assert!(!point_in_triangle(&point![3, 2], &a, &b, &c));
+        assert!(!point_in_triangle(&point![-1, 1], &a, &b, &c));
+    }
+
+    #[test]
+    fn test_point_in_float_triangle() {
+        let (a, b, c) = (point![0.0, 0.0], point![1.0, 0.0], point![0.0, 1.0]);
+
+        assert!(point_in_triangle(&point![0.25, 0.25], &a, &b, &c));
+        assert!(point_in_triangle(&point![0.5, 0.0], &a, &b, &c));
+        assert!(!point_in_triangle(&point![0.75, 0.5], &a, &b, &c));
+    }
+
+    #[test]
+    fn test_point_in_flat_triangle() {
+        assert!(!point_in_triangle(&point![1, 1], &point![0, 0], &point![1, 1], &point![2, 2]));
+    }
+
+    #[test]
+    fn test_polygon_area() {
+        let square = [point![0, 0], point![1, 0], point![1, 1], point![0, 1]];
+
+        assert_eq!(polygon_area(&square), 1);
+        assert_eq!(polygon_double_area(&[point![0, 0], point![0, 1], point![1, 1], point![1, 0]]), -2);
+        assert_eq!(polygon_double_area(&[point![0, 0], point![3, 0], point![0, 3]]), 9);
+        assert_eq!(polygon_double_area::<i32>(&[]), 0);
+    }
+
+    #[test]
+    fn test_box_and_not_box() {
+        use crate::{BBox, Holds, Walkable};
+
+        let outer = BBox::from(point![0, 0]..point![8, 6]);
+        let hole = BBox::from(point![2, 1]..=point![5, 3]);
+        let region = All(vec![Box::new(outer), Box::new(Not(Box::new(hole)))]);
+
+        let grid = BBox::from(point![-2, -2]..point![10, 8]);
+
+        for pt in grid.walk().unwrap().iter() {
+            assert_eq!(region.test(&pt), outer.holds(&pt) && !hole.holds(&pt), "mismatch on {pt}");
+        }
+    }
+
+    #[test]
+    fn test_empty_combinators() {
+        assert!(All::<i32, 2>(Vec::new()).test(&point![0, 0]));
+        assert!(!Any::<i32, 2>(Vec::new()).test(&point![0, 0]));
+    }
+
+    #[test]
+    fn test_nested_combinators() {
+        let region = Any::<i32, 2>(vec![
+            Box::new(point![0, 0]..point![2, 2]),
+            Box::new(All(vec![
+                Box::new(|pt: &Point2<i32>| pt.x == pt.y),
+                Box::new(Not(Box::new(..=point![5, 5]))),
+            ])),
+        ]);
+
+        assert!(region.test(&point![1, 0]));
+        assert!(region.test(&point![7, 7]));
+        assert!(!region.test(&point![4, 4]));
+        assert!(!region.test(&point![7, 6]));
+    }
+}
@@ -0,0 +1,20 @@
+//! Re-exports the most used types and traits, to be glob imported
+//!
+//! # Example
+//! ```
+//! use nalgebra::point;
+//! use pythagore::prelude::*;
+//!
+//! let bbox = BBox::from(point![0, 0]..point![5, 5]);
+//!
+//! assert!(bbox.holds(&point![2, 2]));
+//! assert!(bbox.overlaps(&(point![4, 4]..point![8, 8])));
+//! assert_eq!(bbox.walk().unwrap().iter().count(), 25);
+//!
+//! // Conversion and predicate traits are included too
+//! assert!(bbox.intersects(point![4, 4]..point![8, 8]));
+//! assert!(bbox.test(&(2, 3).into_point()));
+//! ```
+
+pub use crate::{AxisRange, BBox, BBoxBuilder, BBoxWalker, Segment};
+pub use crate::traits::{DimBounds, Holds, Intersection, IntoBBox, IntoPoint, IsRangeEmpty, Overlaps, PointBounds, PointPredicate, Walkable};
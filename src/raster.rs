@@ -0,0 +1,274 @@
+use na::{Point, Scalar};
+use num_traits::PrimInt;
+
+use crate::{BBox, Holds};
+
+/// Iterator over the lattice points of a line, see [`bresenham`]
+#[derive(Clone, Debug)]
+pub struct Bresenham<N: Scalar, const D: usize> {
+    base: Point<N, D>,
+    steps: [(u128, bool); D],
+    errors: [u128; D],
+    length: u128,
+    next: Option<u128>,
+}
+
+/// Rasterizes the line going from a to b (both included) on the integer lattice, in any dimension.
+/// Each point is the exact point of the line on the longest axis step, rounded to the nearest lattice
+/// point, with ties rounded down. As ties do not depend on direction, reversing endpoints yields the
+/// same points in reverse order.
+///
+/// # Example
+/// ```
+/// use nalgebra::point;
+/// use pythagore::raster::bresenham;
+///
+/// assert_eq!(
+///     bresenham(&point![0, 0], &point![5, 2]).collect::<Vec<_>>(),
+///     vec![point![0, 0], point![1, 0], point![2, 1], point![3, 1], point![4, 2], point![5, 2]]
+/// );
+/// ```
+pub fn bresenham<N: PrimInt + Scalar, const D: usize>(a: &Point<N, D>, b: &Point<N, D>) -> Bresenham<N, D> {
+    let mut steps = [(0, true); D];
+    let mut length = 0;
+
+    for (idx, step) in steps.iter_mut().enumerate() {
+        let s = unsafe { *a.get_unchecked(idx) };
+        let e = unsafe { *b.get_unchecked(idx) };
+
+        *step = if s <= e { (abs_diff(s, e), true) } else { (abs_diff(e, s), false) };
+        length = length.max(step.0);
+    }
+
+    Bresenham {
+        base: *a,
+        steps,
+        errors: [0; D],
+        length,
+        next: Some(0),
+    }
+}
+
+/// Computes b - a, with a <= b, without overflowing on signed scalars
+fn abs_diff<N: PrimInt>(a: N, b: N) -> u128 {
+    match (a.to_i128(), b.to_i128()) {
+        (Some(a), Some(b)) => b.abs_diff(a),
+        _ => b.to_u128().unwrap_or(0) - a.to_u128().unwrap_or(0),
+    }
+}
+
+impl<N: PrimInt + Scalar, const D: usize> Iterator for Bresenham<N, D> {
+    type Item = Point<N, D>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let idx = self.next?;
+        let mut point = self.base;
+
+        // Exact offset on each axis is base + error / length, rounded half down in coordinates
+        if self.length > 0 {
+            for ((x, (_, forward)), err) in point.iter_mut().zip(self.steps.iter()).zip(self.errors.iter()) {
+                if *forward && *err > self.length - *err {
+                    *x = *x + N::one();
+                } else if !*forward && *err >= self.length - *err && *err > 0 {
+                    *x = *x - N::one();
+                }
+            }
+        }
+
+        if idx < self.length {
+            for ((x, (delta, forward)), err) in self.base.iter_mut().zip(self.steps.iter()).zip(self.errors.iter_mut()) {
+                if *err >= self.length - *delta {
+                    *err -= self.length - *delta;
+                    *x = if *forward { *x + N::one() } else { *x - N::one() };
+                } else {
+                    *err += *delta;
+                }
+            }
+
+            self.next = Some(idx + 1);
+        } else {
+            self.next = None;
+        }
+
+        Some(point)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.next.and_then(|idx| (self.length - idx).checked_add(1)?.try_into().ok());
+
+        match (self.next, remaining) {
+            (None, _) => (0, Some(0)),
+            (Some(_), Some(n)) => (n, Some(n)),
+            (Some(_), None) => (usize::MAX, None),
+        }
+    }
+}
+
+impl<N: PrimInt + Scalar, const D: usize> BBox<N, D> {
+    /// Rasterizes the line going from a to b, as [`bresenham`] does, keeping only points held by bbox.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::BBox;
+    ///
+    /// let bbox = BBox::from(point![0, 0]..point![3, 3]);
+    ///
+    /// assert_eq!(
+    ///     bbox.clip_bresenham(&point![-1, -1], &point![4, 4]).collect::<Vec<_>>(),
+    ///     vec![point![0, 0], point![1, 1], point![2, 2]]
+    /// );
+    /// ```
+    pub fn clip_bresenham(&self, a: &Point<N, D>, b: &Point<N, D>) -> impl Iterator<Item = Point<N, D>> + '_ {
+        bresenham(a, b).filter(|point| self.holds(point))
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use na::point;
+    use crate::Walkable;
+    use super::*;
+
+    #[test]
+    fn test_single_point() {
+        assert_eq!(bresenham(&point![2, 3], &point![2, 3]).collect::<Vec<_>>(), [point![2, 3]]);
+    }
+
+    #[test]
+    fn test_axis_lines() {
+        assert_eq!(
+            bresenham(&point![0, 1], &point![3, 1]).collect::<Vec<_>>(),
+            [point![0, 1], point![1, 1], point![2, 1], point![3, 1]]
+        );
+        assert_eq!(
+            bresenham(&point![2u8, 3], &point![2, 0]).collect::<Vec<_>>(),
+            [point![2, 3], point![2, 2], point![2, 1], point![2, 0]]
+        );
+    }
+
+    #[test]
+    fn test_diagonal_lines() {
+        assert_eq!(
+            bresenham(&point![0, 0], &point![3, -3]).collect::<Vec<_>>(),
+            [point![0, 0], point![1, -1], point![2, -2], point![3, -3]]
+        );
+        assert_eq!(
+            bresenham(&point![0, 0], &point![2, 5]).collect::<Vec<_>>(),
+            [point![0, 0], point![0, 1], point![1, 2], point![1, 3], point![2, 4], point![2, 5]]
+        );
+    }
+
+    #[test]
+    fn test_3d_line() {
+        let points: Vec<_> = bresenham(&point![0, 10, -2], &point![7, 4, 1]).collect();
+
+        assert_eq!(points.len(), 8);
+        assert_eq!(points.first(), Some(&point![0, 10, -2]));
+        assert_eq!(points.last(), Some(&point![7, 4, 1]));
+
+        for pair in points.windows(2) {
+            let step = pair[1] - pair[0];
+
+            assert_eq!(step.x, 1);
+            assert!(step.y == 0 || step.y == -1);
+            assert!(step.z == 0 || step.z == 1);
+        }
+    }
+
+    #[test]
+    fn test_reversed_endpoints() {
+        for (a, b) in [(point![0, 0], point![5, 2]), (point![-3, 7], point![4, -1]), (point![1, 1], point![9, 4])] {
+            let forward: Vec<_> = bresenham(&a, &b).collect();
+            let mut backward: Vec<_> = bresenham(&b, &a).collect();
+            backward.reverse();
+
+            assert_eq!(forward, backward);
+        }
+    }
+
+    #[test]
+    fn test_size_hint() {
+        let mut line = bresenham(&point![0, 0], &point![5, 2]);
+        line.next();
+
+        assert_eq!(line.size_hint(), (5, Some(5)));
+        assert_eq!(line.count(), 5);
+    }
+
+    #[test]
+    fn test_clip_bresenham() {
+        let bbox = BBox::from(point![0, 0]..point![4, 3]);
+
+        assert_eq!(
+            bbox.clip_bresenham(&point![-2, -1], &point![8, 4]).collect::<Vec<_>>(),
+            [point![0, 0], point![1, 0], point![2, 1], point![3, 1]]
+        );
+        assert_eq!(bbox.clip_bresenham(&point![-2, 5], &point![8, 5]).count(), 0);
+    }
+
+    #[test]
+    fn test_u8_line() {
+        let points: Vec<_> = bresenham(&point![0u8, 0], &point![15u8, 15]).collect();
+
+        assert_eq!(points.len(), 16);
+        assert!(points.iter().enumerate().all(|(idx, pt)| *pt == point![idx as u8, idx as u8]));
+
+        let points: Vec<_> = bresenham(&point![255u8, 0], &point![0u8, 254]).collect();
+
+        assert_eq!(points.len(), 256);
+        assert_eq!(points.first(), Some(&point![255, 0]));
+        assert_eq!(points.last(), Some(&point![0, 254]));
+    }
+
+    #[test]
+    fn test_full_range_i8_line() {
+        let points: Vec<_> = bresenham(&point![-128i8, 127], &point![127i8, -128]).collect();
+
+        assert_eq!(points.len(), 256);
+        assert!(points.iter().enumerate().all(|(idx, pt)| pt.x as i32 == idx as i32 - 128 && pt.y as i32 == 127 - idx as i32));
+    }
+
+    #[test]
+    fn test_large_i32_line() {
+        let mut line = bresenham(&point![0, 0], &point![50_000, 50_000]);
+
+        assert_eq!(line.size_hint(), (50_001, Some(50_001)));
+        assert_eq!(line.nth(25_000), Some(point![25_000, 25_000]));
+        assert_eq!(line.last(), Some(point![50_000, 50_000]));
+
+        let mut line = bresenham(&point![i32::MIN, 0], &point![i32::MAX, 1]);
+
+        let count = usize::try_from(1u64 << 32).ok();
+
+        assert_eq!(line.size_hint(), (count.unwrap_or(usize::MAX), count));
+        assert_eq!(line.next(), Some(point![i32::MIN, 0]));
+        assert_eq!(line.next(), Some(point![i32::MIN + 1, 0]));
+    }
+
+    #[test]
+    fn test_matches_exact_rounding() {
+        let grid = BBox::from(point![-4, -4]..=point![4, 4]);
+
+        for a in grid.walk().unwrap().iter() {
+            for b in grid.walk().unwrap().iter() {
+                let length = (b - a).abs().max().max(1) as i64;
+
+                for (idx, pt) in bresenham(&a, &b).enumerate() {
+                    for axis in 0..2 {
+                        let twice = 2 * idx as i64 * (b[axis] - a[axis]) as i64;
+                        let expected = if twice >= 0 {
+                            a[axis] as i64 + (twice + length - 1) / (2 * length)
+                        } else {
+                            a[axis] as i64 - (length - twice) / (2 * length)
+                        };
+
+                        assert_eq!(pt[axis] as i64, expected, "{a} -> {b} at {idx}");
+                    }
+                }
+            }
+        }
+    }
+}
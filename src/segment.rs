@@ -0,0 +1,164 @@
+use na::{ClosedAdd, ClosedDiv, ClosedMul, ClosedSub, Point, RealField, Scalar, SVector};
+use num_traits::{One, Zero};
+
+use crate::BBox;
+
+/// Segment between two points
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Segment<N: Scalar, const D: usize> {
+    pub a: Point<N, D>,
+    pub b: Point<N, D>,
+}
+
+impl<N: Scalar, const D: usize> Segment<N, D> {
+    /// Builds a segment going from a to b
+    pub fn new(a: Point<N, D>, b: Point<N, D>) -> Segment<N, D> {
+        Segment { a, b }
+    }
+
+    /// Returns vector going from a to b
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::{point, vector};
+    /// use pythagore::Segment;
+    ///
+    /// assert_eq!(Segment::new(point![1, 2], point![4, 6]).direction(), vector![3, 4]);
+    /// ```
+    #[inline]
+    pub fn direction(&self) -> SVector<N, D>
+    where
+        N: ClosedSub
+    {
+        &self.b - &self.a
+    }
+
+    /// Returns squared length of segment, exact on integer scalars
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::Segment;
+    ///
+    /// assert_eq!(Segment::new(point![1, 2], point![4, 6]).length_squared(), 25);
+    /// ```
+    pub fn length_squared(&self) -> N
+    where
+        N: ClosedAdd + ClosedMul + ClosedSub + Zero
+    {
+        let direction = self.direction();
+        direction.dot(&direction)
+    }
+
+    /// Returns length of segment
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::Segment;
+    ///
+    /// assert_eq!(Segment::new(point![1.0, 2.0], point![4.0, 6.0]).length(), 5.0);
+    /// ```
+    #[inline]
+    pub fn length(&self) -> N
+    where
+        N: RealField
+    {
+        self.direction().norm()
+    }
+
+    /// Returns middle point of segment. On integer scalars the middle is rounded toward a.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::Segment;
+    ///
+    /// assert_eq!(Segment::new(point![1.0, 2.0], point![4.0, 6.0]).midpoint(), point![2.5, 4.0]);
+    /// ```
+    pub fn midpoint(&self) -> Point<N, D>
+    where
+        N: ClosedAdd + ClosedDiv + ClosedSub + One
+    {
+        let two = N::one() + N::one();
+        &self.a + self.direction() / two
+    }
+
+    /// Returns point at given parameter along segment: a for 0, b for 1.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::Segment;
+    ///
+    /// assert_eq!(Segment::new(point![0.0, 2.0], point![4.0, 6.0]).point_at(0.25), point![1.0, 3.0]);
+    /// ```
+    pub fn point_at(&self, t: N) -> Point<N, D>
+    where
+        N: ClosedAdd + ClosedMul + ClosedSub
+    {
+        &self.a + self.direction() * t
+    }
+
+    /// Returns the smallest bbox holding the segment
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::{BBox, Segment};
+    ///
+    /// assert_eq!(
+    ///     Segment::new(point![1, 6], point![4, 2]).bbox(),
+    ///     BBox::from(point![1, 2]..=point![4, 6])
+    /// );
+    /// ```
+    #[inline]
+    pub fn bbox(&self) -> BBox<N, D>
+    where
+        N: Copy + PartialOrd
+    {
+        BBox::from_points_included(&self.a, &self.b)
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use na::point;
+    use crate::Holds;
+    use super::*;
+
+    #[test]
+    fn test_length() {
+        let segment = Segment::new(point![-1.0, 1.0], point![2.0, 5.0]);
+
+        assert_eq!(segment.length_squared(), 25.0);
+        assert_eq!(segment.length(), 5.0);
+        assert_eq!(Segment::new(point![0, 0, 0], point![2, 3, 6]).length_squared(), 49);
+    }
+
+    #[test]
+    fn test_midpoint() {
+        let segment = Segment::new(point![-1.0, 1.0], point![2.0, 5.0]);
+
+        assert_eq!(segment.midpoint(), point![0.5, 3.0]);
+        assert_eq!(segment.point_at(0.5), segment.midpoint());
+        assert_eq!(segment.point_at(0.0), segment.a);
+        assert_eq!(segment.point_at(1.0), segment.b);
+    }
+
+    #[test]
+    fn test_int_midpoint() {
+        assert_eq!(Segment::new(point![0, 0], point![3, -3]).midpoint(), point![1, -1]);
+    }
+
+    #[test]
+    fn test_bbox() {
+        let segment = Segment::new(point![2, -1], point![-3, 4]);
+        let bbox = segment.bbox();
+
+        assert!(bbox.holds(&segment.a));
+        assert!(bbox.holds(&segment.b));
+        assert_eq!(bbox, BBox::from(point![-3, -1]..=point![2, 4]));
+    }
+}
@@ -1,15 +1,21 @@
 mod dim_bounds;
 mod holds;
 mod intersection;
+mod into_bbox;
+mod into_point;
 mod is_range_empty;
 mod overlaps;
 mod point_bounds;
+mod point_predicate;
 mod walkable;
 
 pub use dim_bounds::DimBounds;
 pub use holds::Holds;
 pub use intersection::Intersection;
+pub use into_bbox::IntoBBox;
+pub use into_point::IntoPoint;
 pub use is_range_empty::IsRangeEmpty;
 pub use overlaps::Overlaps;
 pub use point_bounds::PointBounds;
+pub use point_predicate::PointPredicate;
 pub use walkable::Walkable;
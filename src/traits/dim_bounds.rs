@@ -1,24 +1,27 @@
-use std::ops::RangeBounds;
+use core::ops::RangeBounds;
 use na::Scalar;
 
 pub trait DimBounds<N: Scalar, const D: usize> {
     type Output: RangeBounds<N>;
 
-    /// Returns object dimension bounds at given dimension.
+    /// Returns object dimension bounds at given dimension, None if dimension is out of bounds.
     ///
     /// # Examples
     /// ```
-    /// use std::ops::Bound::{Excluded, Included};
     /// use nalgebra::point;
-    /// use pythagore::traits::DimBounds;
+    /// use pythagore::DimBounds;
     ///
     /// let bbox = point![0, 0]..point![1, 1];
     ///
-    /// assert_eq!(bbox.get_bounds(0), 0..1);
+    /// assert_eq!(bbox.get_bounds(0), Some(0..1));
+    /// assert_eq!(bbox.get_bounds(2), None);
     /// ```
-    fn get_bounds(&self, dim: usize) -> Self::Output {
-        assert!(dim < D, "Dimension index out of bounds");
-        unsafe { self.get_bounds_unchecked(dim) }
+    fn get_bounds(&self, dim: usize) -> Option<Self::Output> {
+        if dim < D {
+            Some(unsafe { self.get_bounds_unchecked(dim) })
+        } else {
+            None
+        }
     }
 
     /// Returns object dimension bounds at given dimension.
@@ -41,3 +44,28 @@ pub trait DimBounds<N: Scalar, const D: usize> {
     /// ```
     unsafe fn get_bounds_unchecked(&self, dim: usize) -> Self::Output;
 }
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use core::ops::Bound::{Excluded, Included};
+    use na::point;
+    use crate::BBox;
+    use super::*;
+
+    fn bounded_dims<R: DimBounds<i32, 2>>(range: &R) -> usize {
+        (0..4).filter(|dim| range.get_bounds(*dim).is_some()).count()
+    }
+
+    #[test]
+    fn test_out_of_bounds() {
+        assert_eq!(bounded_dims(&(point![0, 0]..point![1, 1])), 2);
+        assert_eq!(bounded_dims(&(point![0, 0]..)), 2);
+        assert_eq!(bounded_dims::<core::ops::RangeFull>(&(..)), 2);
+        assert_eq!(bounded_dims(&(point![0, 0]..=point![1, 1])), 2);
+        assert_eq!(bounded_dims(&(..point![1, 1])), 2);
+        assert_eq!(bounded_dims(&(..=point![1, 1])), 2);
+        assert_eq!(bounded_dims(&(Excluded(point![0, 0]), Included(point![1, 1]))), 2);
+        assert_eq!(bounded_dims(&BBox::from(point![0, 0]..point![1, 1])), 2);
+    }
+}
@@ -0,0 +1,91 @@
+use na::Scalar;
+use crate::BBox;
+
+/// Converts an object into a bbox. Implemented for every type convertible into a bbox (point ranges,
+/// bound tuples, arrays of bound pairs) and for bbox references.
+/// Implement it on your own types to pass them to functions taking `impl IntoBBox`.
+///
+/// # Example
+/// ```
+/// use nalgebra::point;
+/// use pythagore::{BBox, IntoBBox};
+///
+/// struct Rect { x: i32, y: i32, w: i32, h: i32 }
+///
+/// impl IntoBBox<i32, 2> for Rect {
+///     fn into_bbox(self) -> BBox<i32, 2> {
+///         BBox::from(point![self.x, self.y]..point![self.x + self.w, self.y + self.h])
+///     }
+/// }
+///
+/// let bbox = BBox::from(point![0, 0]..point![5, 5]);
+///
+/// assert!(bbox.intersects(Rect { x: 4, y: 4, w: 2, h: 2 }));
+/// ```
+pub trait IntoBBox<N: Scalar, const D: usize> {
+    /// Converts self into a bbox
+    fn into_bbox(self) -> BBox<N, D>;
+}
+
+// Implementations
+impl<N: Scalar, T: Into<BBox<N, D>>, const D: usize> IntoBBox<N, D> for T {
+    #[inline]
+    fn into_bbox(self) -> BBox<N, D> {
+        self.into()
+    }
+}
+
+impl<N: Copy + Scalar, const D: usize> IntoBBox<N, D> for &BBox<N, D> {
+    #[inline]
+    fn into_bbox(self) -> BBox<N, D> {
+        *self
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use core::ops::Bound::{Excluded, Included, Unbounded};
+    use na::point;
+    use super::*;
+
+    struct Rect {
+        x: i32,
+        y: i32,
+        w: i32,
+        h: i32,
+    }
+
+    impl IntoBBox<i32, 2> for Rect {
+        fn into_bbox(self) -> BBox<i32, 2> {
+            BBox::from(point![self.x, self.y]..point![self.x + self.w, self.y + self.h])
+        }
+    }
+
+    #[test]
+    fn test_local_type() {
+        let bbox = BBox::from(point![0, 0]..point![5, 5]);
+
+        assert!(bbox.intersects(Rect { x: 4, y: 4, w: 2, h: 2 }));
+        assert!(bbox.contains_bbox(Rect { x: 1, y: 1, w: 2, h: 2 }));
+        assert_eq!(bbox.union(Rect { x: 4, y: 4, w: 2, h: 2 }), BBox::from(point![0, 0]..point![6, 6]));
+        assert_eq!(bbox.clamped_to(Rect { x: 4, y: 4, w: 2, h: 2 }), BBox::from(point![4, 4]..point![5, 5]));
+        assert_eq!(bbox.walk_within(Rect { x: 4, y: 4, w: 2, h: 2 }).unwrap().point_count(), Some(1));
+    }
+
+    #[test]
+    fn test_blanket_conversions() {
+        let bbox = BBox::from(point![0, 0]..point![5, 5]);
+
+        assert_eq!((point![0, 0]..point![5, 5]).into_bbox(), bbox);
+        assert_eq!((point![0, 0]..).into_bbox(), BBox::from([(Included(0), Unbounded); 2]));
+        assert_eq!(IntoBBox::<i32, 2>::into_bbox(..), BBox::universe());
+        assert_eq!((point![0, 0]..=point![5, 5]).into_bbox(), BBox::from([(Included(0), Included(5)); 2]));
+        assert_eq!((..point![5, 5]).into_bbox(), BBox::from([(Unbounded, Excluded(5)); 2]));
+        assert_eq!((..=point![5, 5]).into_bbox(), BBox::from([(Unbounded, Included(5)); 2]));
+        assert_eq!((Included(point![0, 0]), Excluded(point![5, 5])).into_bbox(), bbox);
+        assert_eq!([(Included(0), Excluded(5)); 2].into_bbox(), bbox);
+        assert_eq!(bbox.into_bbox(), bbox);
+        assert_eq!((&bbox).into_bbox(), bbox);
+    }
+}
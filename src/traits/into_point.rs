@@ -0,0 +1,79 @@
+use na::{Point, Point2, Point3, Scalar};
+
+/// Converts an object into a point. Implemented for points, point references, arrays and
+/// tuples of coordinates (in 2D and 3D).
+/// Implement it on your own types to pass them to functions taking `impl IntoPoint`.
+///
+/// # Example
+/// ```
+/// use nalgebra::point;
+/// use pythagore::IntoPoint;
+///
+/// assert_eq!((1, 2).into_point(), point![1, 2]);
+/// assert_eq!([1, 2, 3].into_point(), point![1, 2, 3]);
+/// ```
+pub trait IntoPoint<N: Scalar, const D: usize> {
+    /// Converts self into a point
+    fn into_point(self) -> Point<N, D>;
+}
+
+// Implementations
+impl<N: Scalar, const D: usize> IntoPoint<N, D> for Point<N, D> {
+    #[inline]
+    fn into_point(self) -> Point<N, D> {
+        self
+    }
+}
+
+impl<N: Copy + Scalar, const D: usize> IntoPoint<N, D> for &Point<N, D> {
+    #[inline]
+    fn into_point(self) -> Point<N, D> {
+        *self
+    }
+}
+
+impl<N: Scalar, const D: usize> IntoPoint<N, D> for [N; D] {
+    #[inline]
+    fn into_point(self) -> Point<N, D> {
+        Point::from(self)
+    }
+}
+
+impl<N: Scalar> IntoPoint<N, 2> for (N, N) {
+    #[inline]
+    fn into_point(self) -> Point2<N> {
+        Point2::new(self.0, self.1)
+    }
+}
+
+impl<N: Scalar> IntoPoint<N, 3> for (N, N, N) {
+    #[inline]
+    fn into_point(self) -> Point3<N> {
+        Point3::new(self.0, self.1, self.2)
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use na::point;
+    use crate::BBox;
+    use super::*;
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(point![1, 2].into_point(), point![1, 2]);
+        assert_eq!((&point![1, 2]).into_point(), point![1, 2]);
+        assert_eq!([1, 2].into_point(), point![1, 2]);
+        assert_eq!((1, 2).into_point(), point![1, 2]);
+        assert_eq!((1, 2, 3).into_point(), point![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_include() {
+        let bbox = BBox::from(point![0, 0]..point![5, 5]);
+
+        assert_eq!(bbox.include((2, 8)), bbox.include(point![2, 8]));
+        assert_eq!(bbox.include([7, 1]), bbox.include(point![7, 1]));
+    }
+}
@@ -1,5 +1,5 @@
-use std::ops::Bound::{self, *};
-use std::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+use core::ops::Bound::{self, *};
+use core::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
 
 /// Tests to known if a range does not contain anything
 pub trait IsRangeEmpty {
@@ -1,5 +1,5 @@
-use std::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
-use std::ops::Bound::{self, Excluded, Included, Unbounded};
+use core::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+use core::ops::Bound::{self, Excluded, Included, Unbounded};
 
 /// Tests if ranges overlaps
 pub trait Overlaps<Rhs = Self> {
@@ -0,0 +1,125 @@
+use core::ops::{Bound, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+use na::{Point, Scalar};
+
+use crate::{BBox, DimBounds, Holds};
+
+/// Object-safe test of points, to store heterogeneous regions as `Box<dyn PointPredicate<N, D>>`.
+/// Implemented for bboxes, point ranges, point bound tuples and closures. Combine predicates at
+/// runtime using [`All`](crate::predicates::All), [`Any`](crate::predicates::Any) and
+/// [`Not`](crate::predicates::Not).
+///
+/// # Example
+/// ```
+/// use nalgebra::point;
+/// use pythagore::{BBox, PointPredicate};
+///
+/// let regions: Vec<Box<dyn PointPredicate<i32, 2>>> = vec![
+///     Box::new(BBox::from(point![0, 0]..point![5, 5])),
+///     Box::new(point![3, 3]..),
+///     Box::new(|pt: &nalgebra::Point2<i32>| pt.x == pt.y),
+/// ];
+///
+/// assert!(regions.iter().all(|region| region.test(&point![4, 4])));
+/// assert!(!regions.iter().all(|region| region.test(&point![3, 4])));
+/// ```
+pub trait PointPredicate<N: Scalar, const D: usize> {
+    /// Tests if given point matches the predicate
+    fn test(&self, pt: &Point<N, D>) -> bool;
+}
+
+// Implementations
+impl<N: PartialOrd + Scalar, const D: usize> PointPredicate<N, D> for BBox<N, D> {
+    #[inline]
+    fn test(&self, pt: &Point<N, D>) -> bool {
+        self.holds(pt)
+    }
+}
+
+impl<F, N: Scalar, const D: usize> PointPredicate<N, D> for F
+where
+    F: Fn(&Point<N, D>) -> bool
+{
+    #[inline]
+    fn test(&self, pt: &Point<N, D>) -> bool {
+        self(pt)
+    }
+}
+
+macro_rules! point_predicate_impl {
+    ($($range:ty),+) => {
+        $(
+            impl<N: Copy + PartialOrd + Scalar, const D: usize> PointPredicate<N, D> for $range {
+                fn test(&self, pt: &Point<N, D>) -> bool {
+                    (0..D).all(|idx| unsafe {
+                        <$range as DimBounds<N, D>>::get_bounds_unchecked(self, idx).contains(pt.get_unchecked(idx))
+                    })
+                }
+            }
+        )+
+    };
+}
+
+point_predicate_impl!(
+    Range<Point<N, D>>,
+    RangeFrom<Point<N, D>>,
+    RangeFull,
+    RangeInclusive<Point<N, D>>,
+    RangeTo<Point<N, D>>,
+    RangeToInclusive<Point<N, D>>,
+    (Bound<Point<N, D>>, Bound<Point<N, D>>)
+);
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use core::ops::Bound::{Excluded, Included, Unbounded};
+    use na::point;
+    use crate::Walkable;
+    use super::*;
+
+    #[test]
+    fn test_matches_bbox_holds() {
+        let grid = BBox::from(point![-2, -2]..=point![6, 6]);
+        let predicates: [&dyn PointPredicate<i32, 2>; 7] = [
+            &(point![0, 0]..point![4, 4]),
+            &(point![1, 0]..),
+            &..,
+            &(point![0, 1]..=point![4, 3]),
+            &(..point![3, 5]),
+            &(..=point![2, 2]),
+            &(Excluded(point![0, 0]), Included(point![4, 3])),
+        ];
+        let bboxes = [
+            BBox::from(point![0, 0]..point![4, 4]),
+            BBox::from(point![1, 0]..),
+            BBox::from(..),
+            BBox::from(point![0, 1]..=point![4, 3]),
+            BBox::from(..point![3, 5]),
+            BBox::from(..=point![2, 2]),
+            BBox::from([(Excluded(0), Included(4)), (Excluded(0), Included(3))]),
+        ];
+
+        for (predicate, bbox) in predicates.iter().zip(bboxes.iter()) {
+            for pt in grid.walk().unwrap().iter() {
+                assert_eq!(predicate.test(&pt), bbox.holds(&pt), "mismatch on {pt}");
+                assert_eq!(bbox.test(&pt), bbox.holds(&pt), "mismatch on {pt}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_unbounded_tuple() {
+        let tuple = (Unbounded, Excluded(point![2, 2]));
+
+        assert!(tuple.test(&point![-10, 1]));
+        assert!(!tuple.test(&point![1, 2]));
+    }
+
+    #[test]
+    fn test_closure() {
+        let diagonal = |pt: &Point<i32, 2>| pt.x == pt.y;
+
+        assert!(diagonal.test(&point![3, 3]));
+        assert!(!diagonal.test(&point![3, 2]));
+    }
+}
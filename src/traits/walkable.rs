@@ -1,6 +1,11 @@
+use core::ops::AddAssign;
 use na::{Point, Scalar};
+use num_traits::One;
 use crate::BBoxWalker;
+use crate::bbox_walker::WalkIter;
 
+/// Objects whose lattice points can be walked through. Implementations move excluded bounds with
+/// checked arithmetic, so they require integer like scalars: float boxes and ranges are not walkable.
 pub trait Walkable<N: Scalar, const D: usize> {
     fn first_point(&self) -> Option<Point<N, D>>;
     fn last_point(&self) -> Option<Point<N, D>>;
@@ -12,4 +17,21 @@ pub trait Walkable<N: Scalar, const D: usize> {
             (_, None) => Err("No last point defined"),
         }
     }
+
+    /// Returns an iterator over all points from first to last point.
+    /// Returns None if first or last point is not defined.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::point;
+    /// use pythagore::Walkable;
+    ///
+    /// assert_eq!((point![0, 0]..=point![2, 2]).iter_points().unwrap().count(), 9);
+    /// ```
+    fn iter_points(&self) -> Option<WalkIter<N, D>>
+    where
+        N: AddAssign + Copy + One + Ord
+    {
+        self.walk().ok().map(WalkIter::new)
+    }
 }
\ No newline at end of file
@@ -1,3 +1,9 @@
+#[cfg(feature = "wasm-walker")]
+mod grid_walker_2d;
+
+#[cfg(any(feature = "wasm-point-int", feature = "wasm-point-real", feature = "wasm-vector-int", feature = "wasm-vector-real"))]
+mod object;
+
 #[cfg(feature = "wasm-point-int")]
 mod point_int_2d;
 
@@ -10,6 +16,9 @@ mod vector_int_2d;
 #[cfg(feature = "wasm-vector-real")]
 mod vector_real_2d;
 
+#[cfg(feature = "wasm-walker")]
+pub use grid_walker_2d::GridWalker2D;
+
 #[cfg(feature = "wasm-point-int")]
 pub use point_int_2d::PointInt2D;
 
@@ -0,0 +1,127 @@
+use alloc::vec::Vec;
+use js_sys::{Array, Object, Reflect};
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::prelude::{wasm_bindgen, JsError, JsValue};
+use wasm_bindgen::{JsCast, UnwrapThrowExt};
+
+use crate::BBoxWalker;
+use crate::bbox_walker::WalkIter;
+use crate::wasm::PointInt2D;
+
+/// Walks all integer points between two points (both included), in xy order.
+/// Walkers are iterable, so `for (const p of walker)` walks all points from the first one.
+#[wasm_bindgen]
+pub struct GridWalker2D {
+    iter: WalkIter<i32, 2>,
+}
+
+#[wasm_bindgen]
+impl GridWalker2D {
+    // Statics
+    /// Creates a new walker going from first to last point (included)
+    #[wasm_bindgen(constructor)]
+    pub fn new(first: &PointInt2D, last: &PointInt2D) -> GridWalker2D {
+        GridWalker2D {
+            iter: WalkIter::new(BBoxWalker::new(*first.as_ref(), *last.as_ref())),
+        }
+    }
+
+    // Methods
+    /// Returns next point, or undefined when all points have been walked through
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<PointInt2D> {
+        self.iter.next().map(PointInt2D::from)
+    }
+
+    /// Restarts walk from first point
+    pub fn reset(&mut self) {
+        self.iter = WalkIter::new(*self.iter.walker());
+    }
+
+    /// Total number of walked points, undefined if it overflows
+    pub fn count(&self) -> Option<usize> {
+        self.iter.walker().point_count()
+    }
+
+    /// Returns a js iterator over all walked points from the first one, independent of next calls
+    pub fn values(&self) -> js_sys::Iterator {
+        let mut iter = WalkIter::new(*self.iter.walker());
+
+        let next = Closure::<dyn FnMut() -> Object>::new(move || {
+            let result = Object::new();
+
+            match iter.next() {
+                Some(point) => {
+                    Reflect::set(&result, &JsValue::from_str("value"), &PointInt2D::from(point).into()).unwrap_throw();
+                    Reflect::set(&result, &JsValue::from_str("done"), &JsValue::FALSE).unwrap_throw();
+                }
+                None => {
+                    Reflect::set(&result, &JsValue::from_str("done"), &JsValue::TRUE).unwrap_throw();
+                }
+            }
+
+            result
+        });
+
+        // Inherits from %IteratorPrototype%, whose [Symbol.iterator] returns the iterator itself
+        let proto = Object::get_prototype_of(&Object::get_prototype_of(&Array::new().values()));
+        let obj = Object::create(&proto);
+        Reflect::set(&obj, &JsValue::from_str("next"), &next.into_js_value()).unwrap_throw();
+
+        obj.unchecked_into()
+    }
+
+    /// Same as values, allowing to use walkers in `for..of` loops
+    #[wasm_bindgen(js_name = "[Symbol.iterator]")]
+    pub fn js_iterator(&self) -> js_sys::Iterator {
+        self.values()
+    }
+
+    /// Returns all walked points, failing if there are more than max points
+    #[wasm_bindgen(js_name = toArray)]
+    pub fn to_array(&self, max: usize) -> Result<Vec<PointInt2D>, JsError> {
+        match self.count() {
+            Some(count) if count > max => {
+                return Err(JsError::new(&format!("Walker has {count} points, more than the maximum of {max}")));
+            }
+            None => return Err(JsError::new(&format!("Walker has more points than the maximum of {max}"))),
+            _ => {}
+        }
+
+        Ok(self.iter.walker().iter().map(PointInt2D::from).collect())
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use super::*;
+
+    #[test]
+    fn test_walk() {
+        let mut walker = GridWalker2D::new(&PointInt2D::new(0, 0), &PointInt2D::new(1, 2));
+
+        assert_eq!(walker.count(), Some(6));
+        assert_eq!(walker.next(), Some(PointInt2D::new(0, 0)));
+        assert_eq!(walker.next(), Some(PointInt2D::new(0, 1)));
+
+        walker.reset();
+
+        let mut points = vec![];
+
+        while let Some(point) = walker.next() {
+            points.push(point);
+        }
+
+        assert_eq!(points.len(), 6);
+        assert_eq!(points, walker.to_array(6).unwrap());
+    }
+
+    #[test]
+    fn test_count_overflow() {
+        let walker = GridWalker2D::new(&PointInt2D::new(i32::MIN, i32::MIN), &PointInt2D::new(i32::MAX, i32::MAX));
+
+        assert_eq!(walker.count(), None);
+    }
+}
@@ -0,0 +1,37 @@
+use js_sys::{Object, Reflect};
+use wasm_bindgen::{JsError, JsValue, UnwrapThrowExt};
+
+/// Builds a plain js object holding given numeric fields
+pub(crate) fn to_object(fields: &[(&str, f64)]) -> Object {
+    let obj = Object::new();
+
+    for (key, value) in fields {
+        Reflect::set(&obj, &JsValue::from_str(key), &JsValue::from_f64(*value)).unwrap_throw();
+    }
+
+    obj
+}
+
+/// Reads a numeric field from a js object, failing if it is missing, not a number or NaN
+pub(crate) fn get_real(obj: &JsValue, key: &str) -> Result<f64, JsError> {
+    let value = Reflect::get(obj, &JsValue::from_str(key))
+        .map_err(|_| JsError::new(&format!("Cannot read field {key}, expected an object")))?;
+
+    match value.as_f64() {
+        Some(x) if x.is_nan() => Err(JsError::new(&format!("Field {key} is NaN"))),
+        Some(x) => Ok(x),
+        None => Err(JsError::new(&format!("Field {key} is missing or is not a number"))),
+    }
+}
+
+/// Reads an integer field from a js object, failing if it is not a 32 bits integer
+#[cfg(any(feature = "wasm-point-int", feature = "wasm-vector-int"))]
+pub(crate) fn get_int(obj: &JsValue, key: &str) -> Result<i32, JsError> {
+    let x = get_real(obj, key)?;
+
+    if x.fract() != 0.0 || x < i32::MIN as f64 || x > i32::MAX as f64 {
+        Err(JsError::new(&format!("Field {key} is not a 32 bits integer")))
+    } else {
+        Ok(x as i32)
+    }
+}
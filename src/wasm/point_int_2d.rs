@@ -1,6 +1,9 @@
-use std::borrow::{Borrow, BorrowMut};
+use core::borrow::{Borrow, BorrowMut};
 use na::Point2;
-use wasm_bindgen::prelude::wasm_bindgen;
+use js_sys::Object;
+use wasm_bindgen::prelude::{wasm_bindgen, JsError, JsValue};
+
+use crate::wasm::object::{get_int, to_object};
 
 #[cfg(feature = "wasm-vector-int")]
 use crate::wasm::vector_int_2d::VectorInt2D;
@@ -24,7 +27,19 @@ impl PointInt2D {
         PointInt2D(Point2::origin())
     }
 
+    /// Creates a new point from a plain object like `{ x: 1, y: 2 }`
+    #[wasm_bindgen(js_name = fromObject)]
+    pub fn from_object(obj: &JsValue) -> Result<PointInt2D, JsError> {
+        Ok(PointInt2D::new(get_int(obj, "x")?, get_int(obj, "y")?))
+    }
+
     // Methods
+    /// Returns a plain object like `{ x: 1, y: 2 }`, used by `JSON.stringify`
+    #[wasm_bindgen(js_name = toJSON)]
+    pub fn to_json(&self) -> Object {
+        to_object(&[("x", self.0.x.into()), ("y", self.0.y.into())])
+    }
+
     pub fn equals(&self, other: &PointInt2D) -> bool {
         self.0 == other.0
     }
@@ -97,6 +112,26 @@ impl From<Point2<i32>> for PointInt2D {
     }
 }
 
+impl From<PointInt2D> for Point2<i32> {
+    fn from(value: PointInt2D) -> Self {
+        value.0
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::IVec2> for PointInt2D {
+    fn from(value: glam::IVec2) -> Self {
+        PointInt2D::new(value.x, value.y)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<PointInt2D> for glam::IVec2 {
+    fn from(value: PointInt2D) -> Self {
+        glam::IVec2::new(value.x(), value.y())
+    }
+}
+
 // Operators
 impl PartialEq for PointInt2D {
     #[inline]
@@ -111,3 +146,37 @@ impl PartialEq<Point2<i32>> for PointInt2D {
         &self.0 == other
     }
 }
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nalgebra_round_trip() {
+        let value = PointInt2D::new(3, -4);
+        let converted = Point2::from(value);
+
+        assert_eq!(converted, Point2::new(3, -4));
+        assert_eq!(PointInt2D::from(converted), value);
+    }
+
+    #[cfg(feature = "wasm-vector-int")]
+    #[test]
+    fn test_ops_match_nalgebra() {
+        let a = PointInt2D::new(3, -4);
+        let b = PointInt2D::new(-1, 2);
+
+        assert_eq!(na::Vector2::from(a.sub(&b)), Point2::from(a) - Point2::from(b));
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn test_glam_round_trip() {
+        let value = PointInt2D::new(3, -4);
+        let converted = glam::IVec2::from(value);
+
+        assert_eq!(converted, glam::IVec2::new(3, -4));
+        assert_eq!(PointInt2D::from(converted), value);
+    }
+}
@@ -1,6 +1,9 @@
-use std::borrow::{Borrow, BorrowMut};
+use core::borrow::{Borrow, BorrowMut};
 use na::Point2;
-use wasm_bindgen::prelude::wasm_bindgen;
+use js_sys::Object;
+use wasm_bindgen::prelude::{wasm_bindgen, JsError, JsValue};
+
+use crate::wasm::object::{get_real, to_object};
 
 #[cfg(feature = "wasm-vector-real")]
 use crate::wasm::vector_real_2d::VectorReal2D;
@@ -24,7 +27,19 @@ impl PointReal2D {
         PointReal2D(Point2::origin())
     }
 
+    /// Creates a new point from a plain object like `{ x: 1.5, y: 2 }`
+    #[wasm_bindgen(js_name = fromObject)]
+    pub fn from_object(obj: &JsValue) -> Result<PointReal2D, JsError> {
+        Ok(PointReal2D::new(get_real(obj, "x")?, get_real(obj, "y")?))
+    }
+
     // Methods
+    /// Returns a plain object like `{ x: 1.5, y: 2 }`, used by `JSON.stringify`
+    #[wasm_bindgen(js_name = toJSON)]
+    pub fn to_json(&self) -> Object {
+        to_object(&[("x", self.0.x), ("y", self.0.y)])
+    }
+
     pub fn equals(&self, other: &PointReal2D) -> bool {
         self.0 == other.0
     }
@@ -97,6 +112,26 @@ impl From<Point2<f64>> for PointReal2D {
     }
 }
 
+impl From<PointReal2D> for Point2<f64> {
+    fn from(value: PointReal2D) -> Self {
+        value.0
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::DVec2> for PointReal2D {
+    fn from(value: glam::DVec2) -> Self {
+        PointReal2D::new(value.x, value.y)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<PointReal2D> for glam::DVec2 {
+    fn from(value: PointReal2D) -> Self {
+        glam::DVec2::new(value.x(), value.y())
+    }
+}
+
 // Operators
 impl PartialEq for PointReal2D {
     #[inline]
@@ -111,3 +146,37 @@ impl PartialEq<Point2<f64>> for PointReal2D {
         &self.0 == other
     }
 }
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nalgebra_round_trip() {
+        let value = PointReal2D::new(3.5, -4.0);
+        let converted = Point2::from(value);
+
+        assert_eq!(converted, Point2::new(3.5, -4.0));
+        assert_eq!(PointReal2D::from(converted), value);
+    }
+
+    #[cfg(feature = "wasm-vector-real")]
+    #[test]
+    fn test_ops_match_nalgebra() {
+        let a = PointReal2D::new(3.5, -4.0);
+        let b = PointReal2D::new(-1.0, 2.5);
+
+        assert_eq!(na::Vector2::from(a.sub(&b)), Point2::from(a) - Point2::from(b));
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn test_glam_round_trip() {
+        let value = PointReal2D::new(1.5, -0.25);
+        let converted = glam::DVec2::from(value);
+
+        assert_eq!(converted, glam::DVec2::new(1.5, -0.25));
+        assert_eq!(PointReal2D::from(converted), value);
+    }
+}
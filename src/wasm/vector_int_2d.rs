@@ -1,6 +1,9 @@
-use std::borrow::{Borrow, BorrowMut};
+use core::borrow::{Borrow, BorrowMut};
 use na::Vector2;
-use wasm_bindgen::prelude::wasm_bindgen;
+use js_sys::Object;
+use wasm_bindgen::prelude::{wasm_bindgen, JsError, JsValue};
+
+use crate::wasm::object::{get_int, to_object};
 
 #[cfg(feature = "wasm-vector-real")]
 use crate::wasm::VectorReal2D;
@@ -23,7 +26,19 @@ impl VectorInt2D {
         VectorInt2D(Vector2::zeros())
     }
 
+    /// Creates a new vector from a plain object like `{ dx: 1, dy: 2 }`
+    #[wasm_bindgen(js_name = fromObject)]
+    pub fn from_object(obj: &JsValue) -> Result<VectorInt2D, JsError> {
+        Ok(VectorInt2D::new(get_int(obj, "dx")?, get_int(obj, "dy")?))
+    }
+
     // Methods
+    /// Returns a plain object like `{ dx: 1, dy: 2 }`, used by `JSON.stringify`
+    #[wasm_bindgen(js_name = toJSON)]
+    pub fn to_json(&self) -> Object {
+        to_object(&[("dx", self.0.x.into()), ("dy", self.0.y.into())])
+    }
+
     pub fn equals(&self, other: &VectorInt2D) -> bool {
         self.0 == other.0
     }
@@ -117,6 +132,26 @@ impl From<Vector2<i32>> for VectorInt2D {
     }
 }
 
+impl From<VectorInt2D> for Vector2<i32> {
+    fn from(value: VectorInt2D) -> Self {
+        value.0
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::IVec2> for VectorInt2D {
+    fn from(value: glam::IVec2) -> Self {
+        VectorInt2D::new(value.x, value.y)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<VectorInt2D> for glam::IVec2 {
+    fn from(value: VectorInt2D) -> Self {
+        glam::IVec2::new(value.dx(), value.dy())
+    }
+}
+
 // Operators
 impl PartialEq for VectorInt2D {
     #[inline]
@@ -124,3 +159,38 @@ impl PartialEq for VectorInt2D {
         self.equals(other)
     }
 }
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nalgebra_round_trip() {
+        let value = VectorInt2D::new(3, -4);
+        let converted = Vector2::from(value);
+
+        assert_eq!(converted, Vector2::new(3, -4));
+        assert_eq!(VectorInt2D::from(converted), value);
+    }
+
+    #[test]
+    fn test_ops_match_nalgebra() {
+        let a = VectorInt2D::new(3, -4);
+        let b = VectorInt2D::new(-1, 2);
+
+        assert_eq!(Vector2::from(a.add(&b)), Vector2::from(a) + Vector2::from(b));
+        assert_eq!(Vector2::from(a.sub(&b)), Vector2::from(a) - Vector2::from(b));
+        assert_eq!(a.dot(&b), Vector2::from(a).dot(&Vector2::from(b)));
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn test_glam_round_trip() {
+        let value = VectorInt2D::new(3, -4);
+        let converted = glam::IVec2::from(value);
+
+        assert_eq!(converted, glam::IVec2::new(3, -4));
+        assert_eq!(VectorInt2D::from(converted), value);
+    }
+}
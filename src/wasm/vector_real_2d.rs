@@ -1,6 +1,9 @@
-use std::borrow::{Borrow, BorrowMut};
+use core::borrow::{Borrow, BorrowMut};
 use na::Vector2;
-use wasm_bindgen::prelude::wasm_bindgen;
+use js_sys::Object;
+use wasm_bindgen::prelude::{wasm_bindgen, JsError, JsValue};
+
+use crate::wasm::object::{get_real, to_object};
 
 /// 2D vector defined in js
 #[wasm_bindgen]
@@ -20,7 +23,19 @@ impl VectorReal2D {
         VectorReal2D(Vector2::zeros())
     }
 
+    /// Creates a new vector from a plain object like `{ dx: 1.5, dy: 2 }`
+    #[wasm_bindgen(js_name = fromObject)]
+    pub fn from_object(obj: &JsValue) -> Result<VectorReal2D, JsError> {
+        Ok(VectorReal2D::new(get_real(obj, "dx")?, get_real(obj, "dy")?))
+    }
+
     // Methods
+    /// Returns a plain object like `{ dx: 1.5, dy: 2 }`, used by `JSON.stringify`
+    #[wasm_bindgen(js_name = toJSON)]
+    pub fn to_json(&self) -> Object {
+        to_object(&[("dx", self.0.x), ("dy", self.0.y)])
+    }
+
     pub fn equals(&self, other: &VectorReal2D) -> bool {
         self.0 == other.0
     }
@@ -113,6 +128,26 @@ impl From<Vector2<f64>> for VectorReal2D {
     }
 }
 
+impl From<VectorReal2D> for Vector2<f64> {
+    fn from(value: VectorReal2D) -> Self {
+        value.0
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::DVec2> for VectorReal2D {
+    fn from(value: glam::DVec2) -> Self {
+        VectorReal2D::new(value.x, value.y)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<VectorReal2D> for glam::DVec2 {
+    fn from(value: VectorReal2D) -> Self {
+        glam::DVec2::new(value.dx(), value.dy())
+    }
+}
+
 // Operators
 impl PartialEq for VectorReal2D {
     #[inline]
@@ -120,3 +155,39 @@ impl PartialEq for VectorReal2D {
         self.equals(other)
     }
 }
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nalgebra_round_trip() {
+        let value = VectorReal2D::new(3.5, -4.0);
+        let converted = Vector2::from(value);
+
+        assert_eq!(converted, Vector2::new(3.5, -4.0));
+        assert_eq!(VectorReal2D::from(converted), value);
+    }
+
+    #[test]
+    fn test_ops_match_nalgebra() {
+        let a = VectorReal2D::new(3.0, -4.0);
+        let b = VectorReal2D::new(-1.5, 2.0);
+
+        assert_eq!(Vector2::from(a.add(&b)), Vector2::from(a) + Vector2::from(b));
+        assert_eq!(Vector2::from(a.sub(&b)), Vector2::from(a) - Vector2::from(b));
+        assert_eq!(a.dot(&b), Vector2::from(a).dot(&Vector2::from(b)));
+        assert_eq!(a.norm(), Vector2::from(a).norm());
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn test_glam_round_trip() {
+        let value = VectorReal2D::new(1.5, -0.25);
+        let converted = glam::DVec2::from(value);
+
+        assert_eq!(converted, glam::DVec2::new(1.5, -0.25));
+        assert_eq!(VectorReal2D::from(converted), value);
+    }
+}
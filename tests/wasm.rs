@@ -0,0 +1,175 @@
+#![cfg(all(target_arch = "wasm32", feature = "wasm"))]
+
+use js_sys::{Object, Reflect, JSON};
+use pythagore::wasm::{GridWalker2D, PointInt2D, PointReal2D, VectorInt2D, VectorReal2D};
+use wasm_bindgen::{JsError, JsValue};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+// Utils
+/// Serializes value with JSON.stringify (using its toJSON method) then parses it back
+fn json_round_trip(value: impl Into<JsValue>) -> JsValue {
+    let text = JSON::stringify(&value.into()).unwrap();
+    JSON::parse(&String::from(text)).unwrap()
+}
+
+/// Builds a plain object holding given fields
+fn object(fields: &[(&str, JsValue)]) -> JsValue {
+    let obj = Object::new();
+
+    for (key, value) in fields {
+        Reflect::set(&obj, &JsValue::from_str(key), value).unwrap();
+    }
+
+    obj.into()
+}
+
+/// Reads the message of the js error thrown by a failed conversion
+fn message(err: JsError) -> String {
+    Reflect::get(&JsValue::from(err), &JsValue::from_str("message")).unwrap().as_string().unwrap()
+}
+
+/// Collects points yielded by a js iterable, as a for..of loop would
+fn collect_points(iterable: &JsValue) -> Vec<PointInt2D> {
+    js_sys::try_iter(iterable).unwrap().expect("value should be iterable")
+        .map(|value| PointInt2D::from_object(&value.unwrap()).unwrap())
+        .collect()
+}
+
+/// Points walked from (0, 0) to (1, 2)
+fn expected_points() -> Vec<PointInt2D> {
+    [(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2)].into_iter()
+        .map(|(x, y)| PointInt2D::new(x, y))
+        .collect()
+}
+
+// Tests
+#[wasm_bindgen_test]
+fn point_int_json_round_trip() {
+    let point = PointInt2D::new(-3, 7);
+
+    assert!(PointInt2D::from_object(&json_round_trip(point)).unwrap().equals(&point));
+}
+
+#[wasm_bindgen_test]
+fn point_real_json_round_trip() {
+    let point = PointReal2D::new(-0.5, 2.25);
+
+    assert!(PointReal2D::from_object(&json_round_trip(point)).unwrap().equals(&point));
+}
+
+#[wasm_bindgen_test]
+fn vector_int_json_round_trip() {
+    let vector = VectorInt2D::new(4, -1);
+
+    assert!(VectorInt2D::from_object(&json_round_trip(vector)).unwrap().equals(&vector));
+}
+
+#[wasm_bindgen_test]
+fn vector_real_json_round_trip() {
+    let vector = VectorReal2D::new(1.5, -3.75);
+
+    assert!(VectorReal2D::from_object(&json_round_trip(vector)).unwrap().equals(&vector));
+}
+
+#[wasm_bindgen_test]
+fn missing_field() {
+    let obj = object(&[("x", JsValue::from_f64(1.0))]);
+
+    assert_eq!(message(PointInt2D::from_object(&obj).unwrap_err()), "Field y is missing or is not a number");
+    assert_eq!(message(PointReal2D::from_object(&obj).unwrap_err()), "Field y is missing or is not a number");
+
+    let obj = object(&[("dy", JsValue::from_f64(1.0))]);
+
+    assert_eq!(message(VectorInt2D::from_object(&obj).unwrap_err()), "Field dx is missing or is not a number");
+    assert_eq!(message(VectorReal2D::from_object(&obj).unwrap_err()), "Field dx is missing or is not a number");
+}
+
+#[wasm_bindgen_test]
+fn not_a_number_field() {
+    let obj = object(&[("x", JsValue::from_str("1")), ("y", JsValue::from_f64(2.0))]);
+
+    assert_eq!(message(PointReal2D::from_object(&obj).unwrap_err()), "Field x is missing or is not a number");
+    assert_eq!(message(PointReal2D::from_object(&JsValue::NULL).unwrap_err()), "Cannot read field x, expected an object");
+}
+
+#[wasm_bindgen_test]
+fn nan_field() {
+    let obj = object(&[("x", JsValue::from_f64(1.0)), ("y", JsValue::from_f64(f64::NAN))]);
+
+    assert_eq!(message(PointInt2D::from_object(&obj).unwrap_err()), "Field y is NaN");
+    assert_eq!(message(PointReal2D::from_object(&obj).unwrap_err()), "Field y is NaN");
+
+    let obj = object(&[("dx", JsValue::from_f64(f64::NAN)), ("dy", JsValue::from_f64(1.0))]);
+
+    assert_eq!(message(VectorInt2D::from_object(&obj).unwrap_err()), "Field dx is NaN");
+    assert_eq!(message(VectorReal2D::from_object(&obj).unwrap_err()), "Field dx is NaN");
+}
+
+#[wasm_bindgen_test]
+fn non_integer_field() {
+    let obj = object(&[("x", JsValue::from_f64(1.5)), ("y", JsValue::from_f64(2.0))]);
+
+    assert_eq!(message(PointInt2D::from_object(&obj).unwrap_err()), "Field x is not a 32 bits integer");
+    assert!(PointReal2D::from_object(&obj).is_ok());
+
+    let obj = object(&[("dx", JsValue::from_f64(1.0)), ("dy", JsValue::from_f64(2.0_f64.powi(31)))]);
+
+    assert_eq!(message(VectorInt2D::from_object(&obj).unwrap_err()), "Field dy is not a 32 bits integer");
+    assert!(VectorReal2D::from_object(&obj).is_ok());
+}
+
+#[wasm_bindgen_test]
+fn grid_walker_next_and_reset() {
+    let mut walker = GridWalker2D::new(&PointInt2D::new(0, 0), &PointInt2D::new(1, 2));
+    let mut points = Vec::new();
+
+    while let Some(point) = walker.next() {
+        points.push(point);
+    }
+
+    assert_eq!(points, expected_points());
+    assert_eq!(walker.next(), None);
+
+    walker.reset();
+
+    assert_eq!(walker.next(), Some(PointInt2D::new(0, 0)));
+}
+
+#[wasm_bindgen_test]
+fn grid_walker_count() {
+    let walker = GridWalker2D::new(&PointInt2D::new(0, 0), &PointInt2D::new(1, 2));
+
+    assert_eq!(walker.count(), Some(6));
+    assert_eq!(GridWalker2D::new(&PointInt2D::new(2, 0), &PointInt2D::new(0, 0)).count(), Some(0));
+    assert_eq!(GridWalker2D::new(&PointInt2D::new(i32::MIN, i32::MIN), &PointInt2D::new(i32::MAX, i32::MAX)).count(), None);
+}
+
+#[wasm_bindgen_test]
+fn grid_walker_to_array_guard() {
+    let walker = GridWalker2D::new(&PointInt2D::new(0, 0), &PointInt2D::new(1, 2));
+
+    assert_eq!(walker.to_array(6).unwrap(), expected_points());
+    assert_eq!(message(walker.to_array(5).unwrap_err()), "Walker has 6 points, more than the maximum of 5");
+
+    let walker = GridWalker2D::new(&PointInt2D::new(i32::MIN, i32::MIN), &PointInt2D::new(i32::MAX, i32::MAX));
+
+    assert_eq!(message(walker.to_array(5).unwrap_err()), "Walker has more points than the maximum of 5");
+}
+
+#[wasm_bindgen_test]
+fn grid_walker_iterator_protocol() {
+    let mut walker = GridWalker2D::new(&PointInt2D::new(0, 0), &PointInt2D::new(1, 2));
+    walker.next();
+
+    // Iterators always start from the first point, and are iterable themselves
+    assert_eq!(collect_points(&walker.values()), expected_points());
+
+    let iterator = walker.values();
+    let result = iterator.next().unwrap();
+
+    assert_eq!(Reflect::get(&result, &JsValue::from_str("done")).unwrap(), JsValue::FALSE);
+    assert!(PointInt2D::from_object(&Reflect::get(&result, &JsValue::from_str("value")).unwrap()).unwrap().equals(&PointInt2D::new(0, 0)));
+
+    // for (const p of walker)
+    assert_eq!(collect_points(&JsValue::from(walker)), expected_points());
+}